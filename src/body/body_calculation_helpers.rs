@@ -1,13 +1,25 @@
+use std::error::Error;
+use std::fmt;
+
 use screeps::constants::extra::{
     MOVE_COST_PLAIN,
     MOVE_COST_ROAD,
 };
+use screeps::constants::{HEAL_POWER, LAB_BOOST_ENERGY, MAX_CREEP_SIZE};
+use screeps::constants::extra::CREEP_HITS_PER_PART;
 use screeps::Part;
 use crate::helpers::functions::{const_ceil_f32, const_floor_f32};
 use crate::boost::boost::{AbstractBoost, BoostCategory};
+use crate::boost::{
+    T1_HEAL_MULTIPLIER, T2_HEAL_MULTIPLIER, T3_HEAL_MULTIPLIER,
+    T1_TOUGH_MULTIPLIER, T2_TOUGH_MULTIPLIER, T3_TOUGH_MULTIPLIER,
+};
 use super::body_calculations::{BoostSelectionConfig, BoostTierChoice};
 use super::PartSpec;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 
 /// Errors that can occur when validating the input to construct a PartsSummary.
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -19,8 +31,25 @@ pub enum PartsSummaryValidationError {
     TooManyParts,
 }
 
+impl fmt::Display for PartsSummaryValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::TooManyBoosts => "More boosts than parts specified",
+            Self::TooManyParts => "Too many parts specified",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Error for PartsSummaryValidationError {}
+
 /// Encapsulates a number of parts, as well as a number of boosts of each tier.
+///
+/// With the `serde` feature enabled, a PartsSummary is represented as a map with the keys
+/// `num_parts`, `num_t1_boosts`, `num_t2_boosts`, and `num_t3_boosts`. The counts are validated
+/// when deserializing, as per [checked_new](PartsSummary::checked_new).
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "PartsSummaryFields"))]
 pub struct PartsSummary {
     num_parts: usize,
     num_t1_boosts: usize,
@@ -28,6 +57,25 @@ pub struct PartsSummary {
     num_t3_boosts: usize,
 }
 
+/// The unvalidated fields of a [PartsSummary], used when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct PartsSummaryFields {
+    num_parts: usize,
+    num_t1_boosts: usize,
+    num_t2_boosts: usize,
+    num_t3_boosts: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<PartsSummaryFields> for PartsSummary {
+    type Error = PartsSummaryValidationError;
+
+    fn try_from(fields: PartsSummaryFields) -> Result<Self, Self::Error> {
+        PartsSummary::checked_new(fields.num_parts, fields.num_t1_boosts, fields.num_t2_boosts, fields.num_t3_boosts)
+    }
+}
+
 impl PartsSummary {
     /// Create a new PartsSummary while validating the input.
     pub const fn checked_new(num_parts: usize, num_t1_boosts: usize, num_t2_boosts: usize, num_t3_boosts: usize) -> Result<PartsSummary, PartsSummaryValidationError> {
@@ -35,7 +83,7 @@ impl PartsSummary {
             return Err(PartsSummaryValidationError::TooManyParts);
         }
 
-        let total_boosts = num_t1_boosts + num_t2_boosts + num_t3_boosts;
+        let total_boosts = num_t1_boosts.saturating_add(num_t2_boosts).saturating_add(num_t3_boosts);
 
         if total_boosts > num_parts {
             Err(PartsSummaryValidationError::TooManyBoosts)
@@ -1232,6 +1280,204 @@ pub const fn parts_to_absorb_damage(amount: f32, boost_config: &BoostSelectionCo
     parts_to_action_inner_wrapper_f32(BoostCategory::Tough, amount, boost_config)
 }
 
+/// The Tough and Heal parts needed to sustain a particular amount of incoming damage, as
+/// calculated by [parts_to_sustain_damage].
+///
+/// With the `serde` feature enabled, a SustainPartsSummary is represented as a map with the keys
+/// `tough` and `heal`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SustainPartsSummary {
+    /// The Tough parts needed
+    pub tough: PartsSummary,
+
+    /// The Heal parts needed
+    pub heal: PartsSummary,
+}
+
+impl SustainPartsSummary {
+    /// Returns the number of Tough and Heal parts in total
+    pub const fn num_parts(&self) -> usize {
+        self.tough.num_parts() + self.heal.num_parts()
+    }
+
+    /// Returns the energy needed to spawn the parts and boost them in a lab
+    pub const fn energy_cost(&self) -> u32 {
+        let tough_boosted = self.tough.num_parts() - self.tough.num_unboosted_parts();
+        let heal_boosted = self.heal.num_parts() - self.heal.num_unboosted_parts();
+        self.tough.num_parts() as u32 * Part::Tough.cost()
+            + self.heal.num_parts() as u32 * Part::Heal.cost()
+            + (tough_boosted + heal_boosted) as u32 * LAB_BOOST_ENERGY
+    }
+}
+
+/// Returns the lowest and highest boost tier allowed by a boost tier choice.
+const fn boost_tier_range(choice: &BoostTierChoice) -> (usize, usize) {
+    match choice {
+        BoostTierChoice::NoBoosts => (0, 0),
+        BoostTierChoice::T1Only => (1, 1),
+        BoostTierChoice::T2Only => (2, 2),
+        BoostTierChoice::T3Only => (3, 3),
+        BoostTierChoice::UpToT1 => (0, 1),
+        BoostTierChoice::UpToT2 => (0, 2),
+        BoostTierChoice::UpToT3 => (0, 3),
+    }
+}
+
+/// Creates a PartsSummary where every part has the same boost tier.
+const fn uniform_parts_summary(num_parts: usize, tier: usize) -> PartsSummary {
+    match tier {
+        1 => PartsSummary::unchecked_new(num_parts, num_parts, 0, 0),
+        2 => PartsSummary::unchecked_new(num_parts, 0, num_parts, 0),
+        3 => PartsSummary::unchecked_new(num_parts, 0, 0, num_parts),
+        _ => PartsSummary::unchecked_new(num_parts, 0, 0, 0),
+    }
+}
+
+/// Calculates the damage a creep takes from the specified raw damage, when its body starts with
+/// the specified number of full-health Tough parts.
+///
+/// This follows [BodySpec::damage_taken](super::BodySpec::damage_taken), except that the damage
+/// reduction is rounded down, so the result is never less than the damage the engine would apply.
+const fn damage_taken_behind_tough(amount: u32, num_tough_parts: usize, tough_multiplier: f32) -> u32 {
+    let capacity = num_tough_parts as f32 * CREEP_HITS_PER_PART as f32 / tough_multiplier;
+    let damage_absorbed = if amount as f32 <= capacity { amount as f32 } else { capacity };
+    let damage_reduction = const_floor_f32(damage_absorbed * (1.0 - tough_multiplier));
+    amount.saturating_sub(damage_reduction as u32)
+}
+
+/// Calculates the cheapest combination of Tough and Heal parts that lets a creep heal itself
+/// through a specified amount of raw incoming damage per tick, without losing any hits.
+///
+/// The Tough parts go at the front of the body to reduce the damage, and the Heal parts heal the
+/// rest of it back every tick. The cost is the energy to spawn the parts, plus
+/// [LAB_BOOST_ENERGY] for every boosted part.
+///
+/// Each boost tier allowed by the boost configuration is considered separately for the Tough and
+/// the Heal parts, but all of the parts of one type share a single tier, so partial boosts aren't
+/// considered.
+///
+/// The returned number of body parts will total to 50 or less.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_calculations::{BoostSelectionConfig, BoostTierChoice, parts_to_sustain_damage};
+///
+/// // Unboosted Tough parts don't reduce damage, so only Heal parts are worth spawning
+/// const UNBOOSTED: BoostSelectionConfig = BoostSelectionConfig::new(BoostTierChoice::NoBoosts, false);
+/// let summary = parts_to_sustain_damage(120, &UNBOOSTED).unwrap();
+/// assert_eq!(0, summary.tough.num_parts());
+/// assert_eq!(10, summary.heal.num_parts());
+///
+/// // T3 Tough parts cut the damage to 36, which 1 T3 Heal part can heal
+/// const T3: BoostSelectionConfig = BoostSelectionConfig::new(BoostTierChoice::T3Only, false);
+/// let summary = parts_to_sustain_damage(120, &T3).unwrap();
+/// assert_eq!(1, summary.tough.num_t3_parts());
+/// assert_eq!(1, summary.heal.num_t3_parts());
+///
+/// // Far too much damage to sustain with 50 parts
+/// assert!(parts_to_sustain_damage(100000, &T3).is_err());
+/// ```
+pub const fn parts_to_sustain_damage(amount: u32, boost_config: &BoostSelectionConfig) -> Result<SustainPartsSummary, PartsNeededCalculationError> {
+    const TOUGH_MULTIPLIERS: [f32; 4] = [1.0, T1_TOUGH_MULTIPLIER, T2_TOUGH_MULTIPLIER, T3_TOUGH_MULTIPLIER];
+    const HEAL_MULTIPLIERS: [u32; 4] = [1, T1_HEAL_MULTIPLIER, T2_HEAL_MULTIPLIER, T3_HEAL_MULTIPLIER];
+
+    let (min_tier, max_tier) = boost_tier_range(&boost_config.boost_tier_choice);
+    let mut best: Option<SustainPartsSummary> = None;
+
+    let mut tough_tier = min_tier;
+    while tough_tier <= max_tier {
+        let mut heal_tier = min_tier;
+        while heal_tier <= max_tier {
+            let heal_per_part = HEAL_POWER * HEAL_MULTIPLIERS[heal_tier];
+
+            let mut num_tough_parts = 0;
+            while num_tough_parts <= MAX_CREEP_SIZE as usize {
+                let damage_taken = damage_taken_behind_tough(amount, num_tough_parts, TOUGH_MULTIPLIERS[tough_tier]);
+                let num_heal_parts = damage_taken.div_ceil(heal_per_part) as usize;
+
+                if num_tough_parts + num_heal_parts <= MAX_CREEP_SIZE as usize {
+                    let candidate = SustainPartsSummary {
+                        tough: uniform_parts_summary(num_tough_parts, tough_tier),
+                        heal: uniform_parts_summary(num_heal_parts, heal_tier),
+                    };
+
+                    let is_better = match best {
+                        None => true,
+                        Some(current) => {
+                            candidate.energy_cost() < current.energy_cost()
+                                || (candidate.energy_cost() == current.energy_cost() && candidate.num_parts() < current.num_parts())
+                        },
+                    };
+
+                    if is_better {
+                        best = Some(candidate);
+                    }
+                }
+
+                num_tough_parts += 1;
+            }
+
+            heal_tier += 1;
+        }
+
+        tough_tier += 1;
+    }
+
+    match best {
+        Some(summary) => Ok(summary),
+        None => Err(PartsNeededCalculationError::TooManyNeededParts),
+    }
+}
+
+/// Calculates the Claim part configuration necessary to add a specified number of reservation
+/// ticks per tick when reserving a controller.
+///
+/// Claim parts can't be boosted, so no boost configuration is needed and the returned summary will
+/// only ever contain unboosted parts.
+///
+/// Note that a reservation counts down by 1 every tick, so to grow a reservation by `n` ticks per
+/// tick, you need to reserve `n + 1` ticks per tick.
+///
+/// The returned number of body parts will total to 50 or less.
+///
+/// ```rust
+/// use screeps::{CONTROLLER_RESERVE, CONTROLLER_RESERVE_MAX, CREEP_CLAIM_LIFE_TIME};
+/// use screeps_body_utils::body::body_calculations::{PartsSummary, parts_to_reserve_controller};
+///
+/// // How many Claim parts do you need to take a fresh reservation to the maximum within the
+/// // lifetime of a single reserver (ignoring travel time)?
+/// const NET_GAIN_PER_TICK: u32 = CONTROLLER_RESERVE_MAX.div_ceil(CREEP_CLAIM_LIFE_TIME);
+/// const NEEDED_POWER_PER_TICK: u32 = NET_GAIN_PER_TICK + 1;
+/// const EXPECTED_PARTS_NEEDED: usize = NEEDED_POWER_PER_TICK.div_ceil(CONTROLLER_RESERVE) as usize;
+///
+/// const part_totals: [usize; 5] = if let Ok(summary) = parts_to_reserve_controller(NEEDED_POWER_PER_TICK) {
+///   summary.part_totals()
+/// } else {
+///   [0; 5]
+/// };
+///
+/// // Destructure the part totals array for ease of understanding
+/// const total_parts_needed: usize = part_totals[0];
+/// const unboosted_parts_needed: usize = part_totals[1];
+///
+/// // Verify we got the expected number of (unboosted) parts for our goal
+/// assert_eq!(EXPECTED_PARTS_NEEDED, total_parts_needed);
+/// assert_eq!(EXPECTED_PARTS_NEEDED, unboosted_parts_needed);
+///
+/// // No creep can reserve more than 50 ticks per tick
+/// assert!(parts_to_reserve_controller(51).is_err());
+/// ```
+pub const fn parts_to_reserve_controller(amount: u32) -> Result<PartsSummary, PartsNeededCalculationError> {
+    let part = PartSpec::new_unboosted_part(Part::Claim);
+    let num_parts = num_parts_needed_u32(amount, part.get_reserve_controller_amount());
+
+    if num_parts > 50 {
+        return Err(PartsNeededCalculationError::TooManyNeededParts);
+    }
+
+    Ok(PartsSummary::unchecked_new(num_parts, 0, 0, 0))
+}
+
 /// Calculates the Move part configuration necessary to allow the provided body to move "off-road"
 /// without generating fatigue every tick.
 ///
@@ -2351,4 +2597,111 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parts_to_reserve_controller_calculates_correctly() {
+        for num_parts in 0..=50 {
+            let amount = num_parts as u32 * screeps::CONTROLLER_RESERVE;
+            let summary = parts_to_reserve_controller(amount).unwrap();
+            assert_eq!([num_parts, num_parts, 0, 0, 0], summary.part_totals());
+        }
+
+        assert_eq!(Err(PartsNeededCalculationError::TooManyNeededParts), parts_to_reserve_controller(51 * screeps::CONTROLLER_RESERVE));
+        assert_eq!(Err(PartsNeededCalculationError::TooManyNeededParts), parts_to_reserve_controller(u32::MAX));
+    }
+
+    fn sustain_bodyspec(summary: &SustainPartsSummary) -> crate::body::BodySpec {
+        let mut parts = crate::body::body_ordering::part_specs_from_summary(&BoostCategory::Tough, &summary.tough);
+        parts.extend(crate::body::body_ordering::part_specs_from_summary(&BoostCategory::Heal, &summary.heal));
+        parts.push(PartSpec::new_unboosted_part(Part::Move));
+        crate::body::BodySpec::new(&parts)
+    }
+
+    #[test]
+    fn parts_to_sustain_damage_fully_heals_the_damage() {
+        let boost_tier_choices = [
+            BoostTierChoice::NoBoosts,
+            BoostTierChoice::T1Only,
+            BoostTierChoice::T2Only,
+            BoostTierChoice::T3Only,
+            BoostTierChoice::UpToT3,
+        ];
+
+        for boost_tier_choice in boost_tier_choices {
+            let boost_config = BoostSelectionConfig::new(boost_tier_choice, false);
+            for amount in [0, 1, 12, 100, 150, 333, 600, 1200, 1800, 2400] {
+                let Ok(summary) = parts_to_sustain_damage(amount, &boost_config) else {
+                    continue;
+                };
+
+                let bodyspec = sustain_bodyspec(&summary);
+                let result = bodyspec.apply_damage_and_heal(amount, bodyspec.heal_amount());
+                assert_eq!(bodyspec, result, "Boost tier choice: {:?}, amount: {}", boost_tier_choice, amount);
+                assert!(summary.num_parts() <= 50);
+            }
+        }
+    }
+
+    #[test]
+    fn parts_to_sustain_damage_is_no_more_expensive_than_healing_alone() {
+        for boost_tier_choice in [BoostTierChoice::T1Only, BoostTierChoice::T2Only, BoostTierChoice::T3Only] {
+            let boost_config = BoostSelectionConfig::new(boost_tier_choice, false);
+            for amount in [50, 300, 600, 1200] {
+                let summary = parts_to_sustain_damage(amount, &boost_config).unwrap();
+                let heal_only = SustainPartsSummary {
+                    tough: PartsSummary::unchecked_new(0, 0, 0, 0),
+                    heal: parts_to_heal(amount, &boost_config).unwrap(),
+                };
+                assert!(summary.energy_cost() <= heal_only.energy_cost(), "Boost tier choice: {:?}, amount: {}", boost_tier_choice, amount);
+            }
+        }
+    }
+
+    #[test]
+    fn parts_to_sustain_damage_calculates_correctly() {
+        let t3 = BoostSelectionConfig::new(BoostTierChoice::T3Only, false);
+
+        // 2 T3 Tough parts absorb 666 raw damage, leaving 600 * 0.3 = 180 to heal with 4 T3 Heal
+        // parts
+        let summary = parts_to_sustain_damage(600, &t3).unwrap();
+        assert_eq!([2, 0, 0, 0, 2], summary.tough.part_totals());
+        assert_eq!([4, 0, 0, 0, 4], summary.heal.part_totals());
+        assert_eq!(2 * (10 + 20) + 4 * (250 + 20), summary.energy_cost());
+
+        // Allowing lower tiers can't make it more expensive
+        let up_to_t3 = BoostSelectionConfig::new(BoostTierChoice::UpToT3, false);
+        assert_eq!(summary, parts_to_sustain_damage(600, &up_to_t3).unwrap());
+
+        // No damage needs no parts
+        let summary = parts_to_sustain_damage(0, &t3).unwrap();
+        assert_eq!(0, summary.num_parts());
+
+        // 6 towers at close range can be sustained, but not much more
+        let summary = parts_to_sustain_damage(3600, &t3).unwrap();
+        assert_eq!(11, summary.tough.num_t3_parts());
+        assert_eq!(23, summary.heal.num_t3_parts());
+        assert_eq!(Err(PartsNeededCalculationError::TooManyNeededParts), parts_to_sustain_damage(6000, &t3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parts_summary_serializes_to_documented_representation() {
+        let summary = PartsSummary::checked_new(10, 1, 2, 3).unwrap();
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(r#"{"num_parts":10,"num_t1_boosts":1,"num_t2_boosts":2,"num_t3_boosts":3}"#, json);
+        assert_eq!(summary, serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parts_summary_deserialization_validates_counts() {
+        let result: Result<PartsSummary, _> = serde_json::from_str(r#"{"num_parts":51,"num_t1_boosts":0,"num_t2_boosts":0,"num_t3_boosts":0}"#);
+        assert!(result.is_err());
+
+        let result: Result<PartsSummary, _> = serde_json::from_str(r#"{"num_parts":5,"num_t1_boosts":2,"num_t2_boosts":2,"num_t3_boosts":2}"#);
+        assert!(result.is_err());
+
+        let result: Result<PartsSummary, _> = serde_json::from_str(&format!(r#"{{"num_parts":5,"num_t1_boosts":{},"num_t2_boosts":{},"num_t3_boosts":0}}"#, usize::MAX, usize::MAX));
+        assert!(result.is_err());
+    }
 }
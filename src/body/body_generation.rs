@@ -3,9 +3,10 @@ use std::error::Error;
 use std::fmt;
 
 use screeps::Part;
-use screeps::constants::MAX_CREEP_SIZE;
+use screeps::constants::{MAX_CREEP_SIZE, ResourceType};
 
 use crate::body::{BodySpec, PartSpec};
+use crate::boost::AbstractBoost;
 
 /// Dedicated error describing what went wrong when parsing a body spec.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -13,6 +14,7 @@ pub enum GenerateBodyError {
     InvalidString,
     TooManyBodyParts,
     InvalidMultiplier,
+    InvalidBoost,
 }
 
 impl fmt::Display for GenerateBodyError {
@@ -21,6 +23,7 @@ impl fmt::Display for GenerateBodyError {
             Self::InvalidString => "Body string is invalid",
             Self::TooManyBodyParts => "Too many parts specified",
             Self::InvalidMultiplier => "Invalid multiplier",
+            Self::InvalidBoost => "Invalid boost",
         };
         write!(f, "{}", s)
     }
@@ -28,12 +31,112 @@ impl fmt::Display for GenerateBodyError {
 
 impl Error for GenerateBodyError {}
 
+impl From<BodyStringParseError> for GenerateBodyError {
+    fn from(error: BodyStringParseError) -> Self {
+        match error {
+            BodyStringParseError::UnexpectedCharacter { .. } => Self::InvalidString,
+            BodyStringParseError::UnexpectedEnd { .. } => Self::InvalidString,
+            BodyStringParseError::InvalidMultiplier { .. } => Self::InvalidMultiplier,
+            BodyStringParseError::InvalidBoost { .. } => Self::InvalidBoost,
+            BodyStringParseError::TooManyBodyParts { .. } => Self::TooManyBodyParts,
+        }
+    }
+}
+
+/// The kinds of tokens that make up a body spec string, used to describe what was expected when
+/// strict parsing fails.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BodyStringToken {
+    /// A digit in a group multiplier
+    Digit,
+
+    /// A character representing a part, as per [convert_character_to_part]
+    Part,
+
+    /// Whitespace separating groups
+    Whitespace,
+
+    /// A `(` starting a parenthesized group
+    OpenParenthesis,
+
+    /// A `)` ending a parenthesized group
+    CloseParenthesis,
+
+    /// A boost annotation, starting with either `:` or `[`
+    BoostAnnotation,
+}
+
+impl fmt::Display for BodyStringToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Digit => "digit",
+            Self::Part => "part character",
+            Self::Whitespace => "whitespace",
+            Self::OpenParenthesis => "'('",
+            Self::CloseParenthesis => "')'",
+            Self::BoostAnnotation => "boost annotation",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Detailed error describing what went wrong, and where, when parsing a body spec string.
+///
+/// All offsets are byte offsets into the body spec string.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BodyStringParseError {
+    /// A character that isn't valid at its position in the string.
+    UnexpectedCharacter {
+        character: char,
+        offset: usize,
+        expected: Vec<BodyStringToken>,
+    },
+
+    /// The string ended while more tokens were needed.
+    UnexpectedEnd {
+        offset: usize,
+        expected: Vec<BodyStringToken>,
+    },
+
+    /// A multiplier that is too large to be represented, starting at the offset.
+    InvalidMultiplier {
+        offset: usize,
+    },
+
+    /// A boost annotation that isn't a valid boost, or doesn't apply to any part in its group,
+    /// starting at the offset.
+    InvalidBoost {
+        offset: usize,
+    },
+
+    /// The string describes more than [MAX_CREEP_SIZE] parts. The actual number of parts
+    /// saturates at [usize::MAX].
+    TooManyBodyParts {
+        actual: usize,
+    },
+}
+
+impl fmt::Display for BodyStringParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join_tokens = |tokens: &[BodyStringToken]| tokens.iter().map(|t| t.to_string()).collect::<Vec<String>>().join(", ");
+        match self {
+            Self::UnexpectedCharacter { character, offset, expected } => write!(f, "Unexpected character {:?} at offset {}, expected one of: {}", character, offset, join_tokens(expected)),
+            Self::UnexpectedEnd { offset, expected } => write!(f, "Unexpected end of string at offset {}, expected one of: {}", offset, join_tokens(expected)),
+            Self::InvalidMultiplier { offset } => write!(f, "Invalid multiplier at offset {}", offset),
+            Self::InvalidBoost { offset } => write!(f, "Invalid boost at offset {}", offset),
+            Self::TooManyBodyParts { actual } => write!(f, "Too many parts specified: {} parts, but the maximum is {}", actual, MAX_CREEP_SIZE),
+        }
+    }
+}
+
+impl Error for BodyStringParseError {}
+
 /// Converts a creep body spec string (i.e. "2MC") into a Vec of Parts that can be used for spawning.
 ///
 /// Valid characters are specified in [convert_character_to_part].
 ///
 /// Characters can be prefixed with a number to indicate how many of that part you want. Consecutive
-/// runs of characters without digits separating them will be grouped together.
+/// runs of characters without digits or whitespace separating them will be grouped together.
 ///
 /// ```rust
 /// use screeps::Part;
@@ -114,40 +217,38 @@ impl Error for GenerateBodyError {}
 /// for p in &body[14..15] {
 ///   assert_eq!(Part::Move, *p);
 /// }
+///
+/// // Groups can be wrapped in parentheses, so that a numeric prefix repeats all of them
+/// let body = generate_body_from_string("5T 2(10R 5H) 10M").unwrap();
+/// assert_eq!(45, body.len());
+/// for p in &body[0..5] {
+///   assert_eq!(Part::Tough, *p);
+/// }
+/// for start in [5, 20] {
+///   for p in &body[start..start + 10] {
+///     assert_eq!(Part::RangedAttack, *p);
+///   }
+///   for p in &body[start + 10..start + 15] {
+///     assert_eq!(Part::Heal, *p);
+///   }
+/// }
+/// for p in &body[35..45] {
+///   assert_eq!(Part::Move, *p);
+/// }
+///
+/// // Parenthesized groups can be nested, and whitespace separates groups
+/// let body = generate_body_from_string("2(2C M)").unwrap();
+/// let expected = generate_body_from_string("CCMCCM").unwrap();
+/// assert_eq!(expected, body);
 /// ```
+///
+/// Parentheses that aren't balanced will result in [GenerateBodyError::InvalidString].
+///
+/// Boost annotations, as described in [generate_bodyspec_from_string], are accepted but have no
+/// effect on the generated parts.
 pub fn generate_body_from_string(body_string: &str) -> Result<Vec<Part>, GenerateBodyError> {
-    let mut res = Vec::new();
-
-    let part_groups = parse_part_groups(body_string);
-
-    for (multiplier_string, parts_string) in part_groups {
-        let multiplier_parse_res = if multiplier_string == "" {
-            Ok(1)
-        } else {
-            multiplier_string.parse::<usize>()
-        };
-        match multiplier_parse_res {
-            Ok(multiplier) => {
-                let parts_vec: Vec<Part> = parts_string.split("").filter_map(convert_character_to_part).collect();
-                if parts_vec.len() > 0 {
-                    let full_parts_vec: Vec<Part> = (&parts_vec).repeat(multiplier);
-                    res.push(full_parts_vec);
-                }
-            },
-            Err(_msg) => {
-                return Err(GenerateBodyError::InvalidMultiplier);
-            },
-        };
-    }
-
-    let output_vec: Vec<_> = res.into_iter().flatten().collect();
-
-    if output_vec.len() > MAX_CREEP_SIZE as usize {
-        Err(GenerateBodyError::TooManyBodyParts)
-    }
-    else {
-        Ok(output_vec)
-    }
+    let bodyspec = generate_bodyspec_from_string(body_string)?;
+    Ok(bodyspec.get_parts())
 }
 
 /// Converts a creep body spec string (i.e. "2MC") into a [BodySpec] that can be used for
@@ -168,10 +269,313 @@ pub fn generate_body_from_string(body_string: &str) -> Result<Vec<Part>, Generat
 /// assert_eq!(Part::Move, parts[1]);
 /// ```
 ///
+/// Groups can also be annotated with a boost, which is applied to every part in the group that
+/// the boost can be used on. Boosts can be given either as the resource name of the compound
+/// after a `:`, terminated by whitespace, a parenthesis, or the end of the string, or as the name of an
+/// [AbstractBoost] in square brackets. An annotation ends the group it's attached to.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_specification::{GenerateBodyError, generate_bodyspec_from_string};
+/// use screeps_body_utils::boost::AbstractBoost;
+///
+/// let bodyspec = generate_bodyspec_from_string("10W:XGH2O 5M:XZHO2").unwrap();
+/// let parts = bodyspec.part_specs();
+/// assert_eq!(15, parts.len());
+/// assert!(parts[..10].iter().all(|p| p.boost == Some(AbstractBoost::T3UpgradeController)));
+/// assert!(parts[10..].iter().all(|p| p.boost == Some(AbstractBoost::T3Move)));
+///
+/// // Boosts only apply to the parts they can be used on
+/// let bodyspec = generate_bodyspec_from_string("6W[T3Harvest]3CM").unwrap();
+/// let parts = bodyspec.part_specs();
+/// assert!(parts[..6].iter().all(|p| p.boost == Some(AbstractBoost::T3Harvest)));
+/// assert!(parts[6..].iter().all(|p| p.boost.is_none()));
+///
+/// // Boosts that don't apply to any part in their group are rejected
+/// let res = generate_bodyspec_from_string("5M[T3Harvest]");
+/// assert_eq!(Err(GenerateBodyError::InvalidBoost), res);
+/// ```
+///
 /// For more details about valid body spec strings, see the documentation for [generate_body_from_string].
 pub fn generate_bodyspec_from_string(body_string: &str) -> Result<BodySpec, GenerateBodyError> {
-    let parts_vec = generate_body_from_string(body_string)?;
-    Ok(BodySpec::from(parts_vec))
+    parse_body_string(body_string, false).map_err(GenerateBodyError::from)
+}
+
+/// Converts a creep body spec string into a Vec of Parts, rejecting anything that isn't part of
+/// the grammar.
+///
+/// This works like [generate_body_from_string], except that unrecognized characters and
+/// multipliers that aren't followed by any parts are errors instead of being ignored. Errors
+/// describe what went wrong and where, which makes this suitable for validating configuration.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::{BodyStringParseError, BodyStringToken, generate_body_from_string, generate_body_from_string_strict};
+///
+/// assert_eq!(generate_body_from_string("6W3M"), Ok(generate_body_from_string_strict("6W3M").unwrap()));
+///
+/// // The lenient parser drops unknown characters
+/// assert_eq!(6, generate_body_from_string("6W3X").unwrap().len());
+///
+/// let res = generate_body_from_string_strict("6W3X");
+/// let expected = BodyStringParseError::UnexpectedCharacter {
+///     character: 'X',
+///     offset: 3,
+///     expected: vec![BodyStringToken::Digit, BodyStringToken::Part, BodyStringToken::Whitespace, BodyStringToken::OpenParenthesis],
+/// };
+/// assert_eq!(Err(expected), res);
+///
+/// // Overflows report how many parts were asked for
+/// let res = generate_body_from_string_strict("2(20R 10H)");
+/// assert_eq!(Err(BodyStringParseError::TooManyBodyParts { actual: 60 }), res);
+/// ```
+pub fn generate_body_from_string_strict(body_string: &str) -> Result<Vec<Part>, BodyStringParseError> {
+    let bodyspec = generate_bodyspec_from_string_strict(body_string)?;
+    Ok(bodyspec.get_parts())
+}
+
+/// Converts a creep body spec string into a [BodySpec], rejecting anything that isn't part of the
+/// grammar.
+///
+/// For more details, see the documentation for [generate_body_from_string_strict] and
+/// [generate_bodyspec_from_string].
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::{BodyStringParseError, generate_bodyspec_from_string_strict};
+///
+/// let res = generate_bodyspec_from_string_strict("10W:XGH2O 5M:XZHO3");
+/// assert_eq!(Err(BodyStringParseError::InvalidBoost { offset: 12 }), res);
+/// ```
+pub fn generate_bodyspec_from_string_strict(body_string: &str) -> Result<BodySpec, BodyStringParseError> {
+    parse_body_string(body_string, true)
+}
+
+/// A group parsed from a body spec string, along with its multiplier, before being expanded into
+/// parts.
+enum ParsedGroup {
+    Parts(Vec<PartSpec>, usize),
+    Nested(Vec<ParsedGroup>, usize),
+}
+
+impl ParsedGroup {
+    /// The number of parts this group expands into, saturating on overflow.
+    fn part_count(&self) -> usize {
+        match self {
+            Self::Parts(parts, multiplier) => parts.len().saturating_mul(*multiplier),
+            Self::Nested(groups, multiplier) => total_part_count(groups).saturating_mul(*multiplier),
+        }
+    }
+
+    /// Appends the parts this group expands into to the body.
+    fn expand_into(&self, body: &mut Vec<PartSpec>) {
+        // Avoids spinning on huge multipliers for groups without any parts
+        if self.part_count() == 0 {
+            return;
+        }
+
+        match self {
+            Self::Parts(parts, multiplier) => {
+                for _ in 0..*multiplier {
+                    body.extend_from_slice(parts);
+                }
+            },
+            Self::Nested(groups, multiplier) => {
+                for _ in 0..*multiplier {
+                    groups.iter().for_each(|g| g.expand_into(body));
+                }
+            },
+        }
+    }
+}
+
+/// The number of parts a list of groups expands into, saturating on overflow.
+fn total_part_count(groups: &[ParsedGroup]) -> usize {
+    groups.iter().fold(0, |total, g| total.saturating_add(g.part_count()))
+}
+
+/// The group currently being parsed.
+#[derive(Default)]
+struct PendingGroup {
+    multiplier_string: String,
+    multiplier_offset: usize,
+
+    /// Set once whitespace follows the multiplier, so that further digits can't extend it
+    multiplier_ended: bool,
+
+    /// Set once any character other than a digit is seen, even if it isn't a known part
+    has_part_characters: bool,
+    parts: Vec<Part>,
+}
+
+impl PendingGroup {
+    fn has_dangling_multiplier(&self) -> bool {
+        !self.multiplier_string.is_empty() && !self.has_part_characters
+    }
+
+    fn take_multiplier(&mut self) -> Result<usize, BodyStringParseError> {
+        let multiplier = parse_multiplier(&self.multiplier_string).map_err(|_| BodyStringParseError::InvalidMultiplier { offset: self.multiplier_offset })?;
+        *self = Self::default();
+        Ok(multiplier)
+    }
+
+    /// Finishes the group, applying the boost to the parts it can be used on.
+    fn finish(&mut self, boost: Option<(AbstractBoost, usize)>) -> Result<ParsedGroup, BodyStringParseError> {
+        if let Some((b, offset)) = boost && !self.parts.contains(&b.associated_part()) {
+            return Err(BodyStringParseError::InvalidBoost { offset });
+        }
+
+        let parts = self.parts.iter()
+            .map(|p| match boost {
+                Some((b, _)) if b.associated_part() == *p => PartSpec::new_boosted_part(*p, b),
+                _ => PartSpec::new_unboosted_part(*p),
+            })
+            .collect();
+
+        let multiplier = self.take_multiplier()?;
+        Ok(ParsedGroup::Parts(parts, multiplier))
+    }
+
+    /// The tokens that could validly come next.
+    fn expected_tokens(&self, nested: bool) -> Vec<BodyStringToken> {
+        let mut expected = Vec::new();
+        if !self.multiplier_ended || self.has_part_characters {
+            expected.push(BodyStringToken::Digit);
+        }
+        expected.extend([BodyStringToken::Part, BodyStringToken::Whitespace, BodyStringToken::OpenParenthesis]);
+        if nested && !self.has_dangling_multiplier() {
+            expected.push(BodyStringToken::CloseParenthesis);
+        }
+        if self.has_part_characters {
+            expected.push(BodyStringToken::BoostAnnotation);
+        }
+        expected
+    }
+}
+
+/// Parses a body spec string into a [BodySpec].
+///
+/// When strict, unrecognized characters and multipliers without any parts are errors, rather than
+/// being ignored.
+fn parse_body_string(body_string: &str, strict: bool) -> Result<BodySpec, BodyStringParseError> {
+    let mut groups: Vec<ParsedGroup> = Vec::new();
+
+    // Holds the enclosing groups and the multiplier for each parenthesized group being parsed
+    let mut enclosing_groups: Vec<(Vec<ParsedGroup>, usize)> = Vec::new();
+
+    let mut pending = PendingGroup::default();
+    let mut chars = body_string.char_indices().peekable();
+    while let Some((offset, character)) = chars.next() {
+        let nested = !enclosing_groups.is_empty();
+        if character.is_whitespace() {
+            // Whitespace ends the current group, but can also separate a multiplier from its
+            // parts
+            if pending.has_part_characters {
+                groups.push(pending.finish(None)?);
+            } else if !pending.multiplier_string.is_empty() {
+                pending.multiplier_ended = true;
+            }
+        }
+        else if character.is_ascii_digit() {
+            if pending.has_part_characters {
+                // If there are previously-parsed-parts, then we've moved to a new group and need
+                // to store the old one before starting on the new group
+                groups.push(pending.finish(None)?);
+            } else if pending.multiplier_ended {
+                if strict {
+                    return Err(BodyStringParseError::UnexpectedCharacter { character, offset, expected: pending.expected_tokens(nested) });
+                }
+                pending = PendingGroup::default();
+            }
+
+            if pending.multiplier_string.is_empty() {
+                pending.multiplier_offset = offset;
+            }
+            pending.multiplier_string.push(character);
+        }
+        else if character == '(' {
+            if pending.has_part_characters {
+                // Parts directly before a parenthesized group form their own group, so the
+                // parenthesized group has no multiplier
+                groups.push(pending.finish(None)?);
+            }
+            let multiplier = pending.take_multiplier()?;
+            enclosing_groups.push((std::mem::take(&mut groups), multiplier));
+        }
+        else if character == ')' {
+            if !nested || (strict && pending.has_dangling_multiplier()) {
+                return Err(BodyStringParseError::UnexpectedCharacter { character, offset, expected: pending.expected_tokens(nested) });
+            }
+
+            if pending.has_part_characters {
+                groups.push(pending.finish(None)?);
+            }
+            pending = PendingGroup::default();
+
+            // Unwrap is safe since we've checked that there's an enclosing group
+            let (mut enclosing, multiplier) = enclosing_groups.pop().unwrap();
+            enclosing.push(ParsedGroup::Nested(groups, multiplier));
+            groups = enclosing;
+        }
+        else if character == ':' || character == '[' {
+            let boost = if character == ':' {
+                let mut resource_string = String::new();
+                while let Some((_, c)) = chars.next_if(|(_, c)| !c.is_whitespace() && *c != '(' && *c != ')') {
+                    resource_string.push(c);
+                }
+                resource_string.parse::<ResourceType>().ok().and_then(|r| AbstractBoost::try_from(r).ok())
+            } else {
+                let mut name_string = String::new();
+                let mut closed = false;
+                for (_, c) in chars.by_ref() {
+                    if c == ']' {
+                        closed = true;
+                        break;
+                    }
+                    name_string.push(c);
+                }
+                name_string.parse::<AbstractBoost>().ok().filter(|_| closed)
+            };
+
+            let boost = boost.ok_or(BodyStringParseError::InvalidBoost { offset })?;
+            groups.push(pending.finish(Some((boost, offset)))?);
+        }
+        else {
+            match convert_character_to_part(character.encode_utf8(&mut [0; 4])) {
+                Some(part) => pending.parts.push(part),
+                None if strict => return Err(BodyStringParseError::UnexpectedCharacter { character, offset, expected: pending.expected_tokens(nested) }),
+                None => (),
+            }
+            pending.has_part_characters = true;
+        }
+    }
+
+    // Every parenthesized group needs to be closed
+    let nested = !enclosing_groups.is_empty();
+    if nested || (strict && pending.has_dangling_multiplier()) {
+        return Err(BodyStringParseError::UnexpectedEnd { offset: body_string.len(), expected: pending.expected_tokens(nested) });
+    }
+
+    // Aggregate the final group into the output
+    if pending.has_part_characters {
+        groups.push(pending.finish(None)?);
+    }
+
+    let actual = total_part_count(&groups);
+    if actual > MAX_CREEP_SIZE as usize {
+        return Err(BodyStringParseError::TooManyBodyParts { actual });
+    }
+
+    let mut body = Vec::with_capacity(actual);
+    groups.iter().for_each(|g| g.expand_into(&mut body));
+    Ok(BodySpec::raw_new(body))
+}
+
+/// Parses a group multiplier, where an empty multiplier means the group appears once.
+fn parse_multiplier(multiplier_string: &str) -> Result<usize, GenerateBodyError> {
+    if multiplier_string.is_empty() {
+        Ok(1)
+    } else {
+        multiplier_string.parse::<usize>().map_err(|_| GenerateBodyError::InvalidMultiplier)
+    }
 }
 
 /// Converts a string slice into a Part.
@@ -214,8 +618,225 @@ pub fn convert_character_to_part(char_slice: &str) -> Option<Part> {
     }
 }
 
+/// Converts a Part into its character in the creep body spec string grammar.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_specification::convert_part_to_character;
+///
+/// assert_eq!(Some("W"), convert_part_to_character(&Part::Work));
+/// ```
+///
+/// This is the inverse of [convert_character_to_part], and uses the same conversion table.
+pub fn convert_part_to_character(part: &Part) -> Option<&'static str> {
+    match part {
+        Part::Move => Some("M"),
+        Part::Work => Some("W"),
+        Part::Carry => Some("C"),
+        Part::Attack => Some("A"),
+        Part::RangedAttack => Some("R"),
+        Part::Tough => Some("T"),
+        Part::Heal => Some("H"),
+        Part::Claim => Some("L"),
+        _ => None,
+    }
+}
+
+/// A single part along with its boost, as encoded in a body spec string.
+type PartToken = (Part, Option<AbstractBoost>);
+
+/// The cost of encoding part of a body as a string, used to pick the best encoding.
+///
+/// Compared in order of: total string length, then number of part characters (preferring more
+/// use of multipliers), then number of groups.
+type EncodingCost = (usize, usize, usize);
+
+/// The cheapest encoding of the parts from some index onwards, along with the end of its first
+/// group, that group's multiplier (None if it has no numeric prefix) and pattern length, and its
+/// boost.
+type GroupEncoding = (EncodingCost, usize, Option<usize>, usize, Option<AbstractBoost>);
+
+/// Determines which boost annotation a group made up of the specified parts would need.
+///
+/// Returns None if the parts can't be expressed as a single group, which happens if they use
+/// more than one boost, or if only some of the parts matching the boost are boosted.
+fn group_boost(tokens: &[PartToken]) -> Option<Option<AbstractBoost>> {
+    let boost = tokens.iter().find_map(|(_, boost)| *boost);
+    let consistent = tokens.iter().all(|(part, part_boost)| match boost {
+        Some(b) if b.associated_part() == *part => *part_boost == boost,
+        _ => part_boost.is_none(),
+    });
+
+    if consistent {
+        Some(boost)
+    } else {
+        None
+    }
+}
+
+/// Calculates the length of the annotation needed for a boost, including the whitespace needed to
+/// terminate it if another group follows.
+fn boost_annotation_len(boost: &Option<AbstractBoost>, is_last_group: bool) -> usize {
+    match boost {
+        Some(b) => 1 + b.associated_resource().to_string().len() + if is_last_group { 0 } else { 1 },
+        None => 0,
+    }
+}
+
+/// Finds the cheapest way to encode a slice of parts as a single group, returning the multiplier
+/// and the length of the repeated pattern.
+fn cheapest_group_encoding(tokens: &[PartToken]) -> (usize, usize) {
+    let len = tokens.len();
+    (1..=len)
+        .filter(|pattern_len| len.is_multiple_of(*pattern_len))
+        .filter(|pattern_len| tokens.chunks(*pattern_len).all(|chunk| chunk == &tokens[..*pattern_len]))
+        .map(|pattern_len| (len / pattern_len, pattern_len))
+        .min_by_key(|(multiplier, pattern_len)| multiplier.to_string().len() + pattern_len)
+        // Unwrap is safe because a pattern the length of the whole slice always matches
+        .unwrap()
+}
+
+/// Appends a single group to a body spec string.
+fn push_group_string(output: &mut String, multiplier: Option<usize>, pattern: &[PartToken], boost: &Option<AbstractBoost>, is_last_group: bool) {
+    if let Some(m) = multiplier {
+        output.push_str(&m.to_string());
+    }
+    output.extend(pattern.iter().filter_map(|(part, _)| convert_part_to_character(part)));
+    if let Some(b) = boost {
+        output.push(':');
+        output.push_str(&b.associated_resource().to_string());
+        if !is_last_group {
+            output.push(' ');
+        }
+    }
+}
+
+/// Converts a list of parts and their boosts into the shortest body spec string without
+/// parenthesized groups that will generate them.
+fn generate_string_from_tokens(tokens: &[PartToken]) -> String {
+    let n = tokens.len();
+
+    // best[i][unprefixed_allowed] holds the cheapest encoding of tokens[i..]. A group can only
+    // leave off its numeric prefix if it's the first group, or if the previous group ends in
+    // whitespace, since the parts would otherwise join the previous group. Segments that can't be
+    // expressed as a single group are skipped.
+    let mut best: Vec<[GroupEncoding; 2]> = vec![[((0, 0, 0), n, None, 0, None); 2]; n + 1];
+    for i in (0..n).rev() {
+        for unprefixed_allowed in [false, true] {
+            let prefixed_groups = (i + 1..=n).filter_map(|j| {
+                let boost = group_boost(&tokens[i..j])?;
+                let (multiplier, pattern_len) = cheapest_group_encoding(&tokens[i..j]);
+                let group_len = multiplier.to_string().len() + pattern_len;
+                Some((j, Some(multiplier), pattern_len, group_len, boost))
+            });
+
+            let unprefixed_groups = (i + 1..=n)
+                .filter(|_| unprefixed_allowed)
+                .filter_map(|j| {
+                    let boost = group_boost(&tokens[i..j])?;
+                    Some((j, None, j - i, j - i, boost))
+                });
+
+            // Prefixed groups come first, so they're preferred when the costs are equal
+            best[i][unprefixed_allowed as usize] = prefixed_groups.chain(unprefixed_groups)
+                .map(|(j, multiplier, pattern_len, group_len, boost)| {
+                    // A boost annotation followed by another group ends in whitespace
+                    let ends_in_whitespace = boost.is_some() && j < n;
+                    let (rest_len, rest_part_chars, rest_groups) = best[j][ends_in_whitespace as usize].0;
+                    let group_len = group_len + boost_annotation_len(&boost, j == n);
+                    let cost = (group_len + rest_len, pattern_len + rest_part_chars, 1 + rest_groups);
+                    (cost, j, multiplier, pattern_len, boost)
+                })
+                .min_by_key(|(cost, ..)| *cost)
+                // Unwrap is safe because a single part can always be expressed as a group
+                .unwrap();
+        }
+    }
+
+    let mut output = String::new();
+    let mut i = 0;
+    let mut unprefixed_allowed = true;
+    while i < n {
+        let (_, j, multiplier, pattern_len, boost) = best[i][unprefixed_allowed as usize];
+        push_group_string(&mut output, multiplier, &tokens[i..i + pattern_len], &boost, j == n);
+        unprefixed_allowed = boost.is_some() && j < n;
+        i = j;
+    }
+
+    output
+}
+
+/// Converts a list of Parts into the shortest creep body spec string without parenthesized groups
+/// that will generate them.
+///
+/// Repeated runs and repeated groups of parts are detected and collapsed using numeric prefixes.
+/// When there are multiple shortest strings, the one that relies most on numeric prefixes is
+/// chosen.
+///
+/// Parenthesized groups are never used, so bodies that repeat a sequence of several groups can
+/// have shorter strings than the one returned.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_specification::{generate_body_from_string, generate_string_from_body};
+///
+/// let body = generate_body_from_string("WWWWWWMMM").unwrap();
+/// assert_eq!("6W3M", generate_string_from_body(&body));
+///
+/// let body = generate_body_from_string("CMCMCMCMCMCMCMCMCMCM").unwrap();
+/// assert_eq!("10CM", generate_string_from_body(&body));
+///
+/// // The first group doesn't need a numeric prefix if it only appears once
+/// let body = vec![Part::Work, Part::Carry, Part::Move];
+/// assert_eq!("WCM", generate_string_from_body(&body));
+///
+/// // Repeated sequences of groups are written out in full
+/// let body = generate_body_from_string("5T 2(10R 5H) 10M").unwrap();
+/// assert_eq!("5T10R5H10R5H10M", generate_string_from_body(&body));
+/// ```
+///
+/// This is the inverse of [generate_body_from_string]; the output will always parse back into
+/// the same body. Just like parsing ignores unrecognized characters, parts without a character
+/// in the grammar are left out of the output.
+pub fn generate_string_from_body(body: &[Part]) -> String {
+    let tokens: Vec<PartToken> = body.iter()
+        .filter(|p| convert_part_to_character(p).is_some())
+        .map(|p| (*p, None))
+        .collect();
+    generate_string_from_tokens(&tokens)
+}
+
+/// Converts a [BodySpec] into the shortest creep body spec string without parenthesized groups
+/// that will generate its parts and boosts.
+///
+/// Boosts are written using the `:RESOURCE` annotation described in
+/// [generate_bodyspec_from_string]. Hits are not part of the grammar, and boosts that don't apply
+/// to the part they're on are left out.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::{generate_bodyspec_from_string, generate_string_from_bodyspec};
+///
+/// let bodyspec = generate_bodyspec_from_string("3R3H6M1RHM").unwrap();
+/// assert_eq!("3R3H6M1RHM", generate_string_from_bodyspec(&bodyspec));
+///
+/// let bodyspec = generate_bodyspec_from_string("10W[T3Harvest] 5M[T2Move]").unwrap();
+/// assert_eq!("10W:XUHO2 5M:ZHO2", generate_string_from_bodyspec(&bodyspec));
+/// ```
+///
+/// For more details, see the documentation for [generate_string_from_body].
+pub fn generate_string_from_bodyspec(bodyspec: &BodySpec) -> String {
+    let tokens: Vec<PartToken> = bodyspec.part_specs().iter()
+        .filter(|p| convert_part_to_character(&p.part).is_some())
+        .map(|p| (p.part, p.boost.filter(|b| b.associated_part() == p.part)))
+        .collect();
+    generate_string_from_tokens(&tokens)
+}
+
 /// Splits up a creep body spec string into individual part groups and multipliers.
 ///
+/// Boost annotations are kept with the parts of the group they end, so they can be applied with
+/// [generate_bodyspec_from_string].
+///
 /// ```rust
 /// use screeps::Part;
 /// use screeps_body_utils::body::body_specification::parse_part_groups;
@@ -226,40 +847,114 @@ pub fn convert_character_to_part(char_slice: &str) -> Option<Part> {
 /// assert_eq!(("5".to_string(), "T".to_string()), groups_vec[0]);
 /// assert_eq!(("2".to_string(), "MC".to_string()), groups_vec[1]);
 /// ```
+///
+/// Parenthesized groups are flattened by repeating the groups inside them. Whitespace separates
+/// groups, and is otherwise ignored.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::parse_part_groups;
+///
+/// let groups_vec = parse_part_groups("5T 2(10R 5H) 10M");
+/// let expected: Vec<(String, String)> = [("5", "T"), ("10", "R"), ("5", "H"), ("10", "R"), ("5", "H"), ("10", "M")]
+///     .into_iter()
+///     .map(|(m, p)| (m.to_string(), p.to_string()))
+///     .collect();
+/// assert_eq!(expected, groups_vec);
+/// ```
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::parse_part_groups;
+///
+/// let groups_vec = parse_part_groups("W:XGH2O 2M[T3Move]C");
+/// assert_eq!(("".to_string(), "W:XGH2O".to_string()), groups_vec[0]);
+/// assert_eq!(("2".to_string(), "M[T3Move]".to_string()), groups_vec[1]);
+/// assert_eq!(("".to_string(), "C".to_string()), groups_vec[2]);
+/// ```
+///
+/// Since this can't report errors, unbalanced parentheses are ignored. Repeating a parenthesized
+/// group stops once there are [MAX_CREEP_SIZE] groups, since a body needing any more could never
+/// be spawned.
 pub fn parse_part_groups(body_string: &str) -> Vec<(String, String)> {
     let mut part_groups = Vec::new();
 
-    let mut multiplier_accumulator_vec: Vec<char> = Vec::new();
-    let mut parts_accumulator_vec: Vec<char> = Vec::new();
-    for character in body_string.chars() {
-        if character.is_ascii_digit() {
-            if parts_accumulator_vec.len() > 0 {
+    // Holds the enclosing groups and the multiplier for each parenthesized group being parsed
+    let mut enclosing_groups: Vec<(Vec<(String, String)>, String)> = Vec::new();
+
+    let mut multiplier_string = String::new();
+    let mut parts_string = String::new();
+    let mut chars = body_string.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character.is_whitespace() {
+            if !parts_string.is_empty() {
+                part_groups.push((std::mem::take(&mut multiplier_string), std::mem::take(&mut parts_string)));
+            }
+        }
+        else if character.is_ascii_digit() {
+            if !parts_string.is_empty() {
                 // If there are previously-parsed-parts, then we've moved to a
                 // new group and need to store the old one before starting on the new group
-                let multipler_string: String = multiplier_accumulator_vec.into_iter().collect();
-                let parts_string: String = parts_accumulator_vec.into_iter().collect();
-                let tpl = (multipler_string, parts_string);
-                part_groups.push(tpl);
+                part_groups.push((std::mem::take(&mut multiplier_string), std::mem::take(&mut parts_string)));
+            }
+            multiplier_string.push(character);
+        }
+        else if character == '(' {
+            if !parts_string.is_empty() {
+                part_groups.push((std::mem::take(&mut multiplier_string), std::mem::take(&mut parts_string)));
+            }
+            enclosing_groups.push((std::mem::take(&mut part_groups), std::mem::take(&mut multiplier_string)));
+        }
+        else if character == ')' {
+            if !parts_string.is_empty() {
+                part_groups.push((std::mem::take(&mut multiplier_string), std::mem::take(&mut parts_string)));
+            }
+            multiplier_string.clear();
 
-                multiplier_accumulator_vec = Vec::new();
-                parts_accumulator_vec = Vec::new();
+            if let Some((mut enclosing_part_groups, group_multiplier_string)) = enclosing_groups.pop() {
+                let repeats = if part_groups.is_empty() {
+                    0
+                } else {
+                    parse_multiplier(&group_multiplier_string).unwrap_or(usize::MAX)
+                };
+                let max_new_groups = (MAX_CREEP_SIZE as usize).saturating_sub(enclosing_part_groups.len());
+                let repeated_groups = std::iter::repeat_n(&part_groups, repeats).flatten().take(max_new_groups);
+                enclosing_part_groups.extend(repeated_groups.cloned());
+                part_groups = enclosing_part_groups;
             }
-            multiplier_accumulator_vec.push(character);
+        }
+        else if character == ':' || character == '[' {
+            // Boost annotations end their group, as per generate_bodyspec_from_string
+            parts_string.push(character);
+            if character == ':' {
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '(' && *c != ')') {
+                    parts_string.push(c);
+                }
+            }
+            else {
+                for c in chars.by_ref() {
+                    parts_string.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            part_groups.push((std::mem::take(&mut multiplier_string), std::mem::take(&mut parts_string)));
         }
         else {
-            parts_accumulator_vec.push(character);
+            parts_string.push(character);
         }
     }
 
     // Aggregate the final group into the output
-    if parts_accumulator_vec.len() > 0 {
-        let multipler_string: String = multiplier_accumulator_vec.into_iter().collect();
-        let parts_string: String = parts_accumulator_vec.into_iter().collect();
-        let tpl = (multipler_string, parts_string);
-        part_groups.push(tpl);
+    if !parts_string.is_empty() {
+        part_groups.push((multiplier_string, parts_string));
+    }
+
+    // Close any parenthesized groups that were left open
+    while let Some((mut enclosing_part_groups, _)) = enclosing_groups.pop() {
+        enclosing_part_groups.extend(part_groups);
+        part_groups = enclosing_part_groups;
     }
 
     // Return the parsed part groups
     part_groups
 }
-
@@ -2,8 +2,11 @@
 use itertools::Itertools;
 
 use screeps::Part;
+use screeps::constants::MAX_CREEP_SIZE;
 
 use super::body_generation;
+use crate::body::{BodySpec, PartSpec};
+use crate::boost::AbstractBoost;
 
 
 static KNOWN_PARTS: [(&str, Part); 8] = [
@@ -222,3 +225,438 @@ fn too_many_parts_returns_error() {
     assert_eq!(error, body_generation::GenerateBodyError::TooManyBodyParts);
 }
 
+
+#[test]
+fn part_to_character_conversion_works_for_known_parts() {
+    for (char_slice, part) in KNOWN_PARTS {
+        assert_eq!(Some(char_slice), body_generation::convert_part_to_character(&part));
+    }
+}
+
+#[test]
+fn body_to_string_produces_shortest_strings() {
+    let test_data = [
+        ("", ""),
+        ("M", "M"),
+        ("MM", "2M"),
+        ("WM", "WM"),
+        ("6W3M", "6W3M"),
+        ("10CM", "10CM"),
+        ("WWMM", "2W2M"),
+        ("WCM", "WCM"),
+        ("WCM2M", "WC3M"),
+        ("3R3H6M1RHM", "3R3H6M1RHM"),
+        ("5T2MC", "5T2MC"),
+        ("25WM", "25WM"),
+        ("10M10M", "20M"),
+        ("4WC4WC", "8WC"),
+        ("TW3M", "TW3M"),
+        ("2TW3M", "2TW3M"),
+        ("WMWMC", "2WM1C"),
+        ("2A2R2A2R", "2AARR"),
+    ];
+
+    for (input, expected) in test_data {
+        let body = body_generation::generate_body_from_string(input).unwrap();
+        let output = body_generation::generate_string_from_body(&body);
+        assert_eq!(expected, output, "Input: {}", input);
+    }
+}
+
+#[test]
+fn body_to_string_round_trips() {
+    let known_characters: Vec<&str> = KNOWN_PARTS.iter().map(|tpl| tpl.0).collect();
+
+    // Exhaustively check every 4-part body made from a handful of parts
+    for combination in std::iter::repeat_n(["W", "C", "M"], 4).multi_cartesian_product() {
+        let body_string = combination.concat();
+        let body = body_generation::generate_body_from_string(&body_string).unwrap();
+        let output = body_generation::generate_string_from_body(&body);
+        assert!(output.len() <= body_string.len());
+        assert_eq!(body, body_generation::generate_body_from_string(&output).unwrap(), "Input: {}, Output: {}", body_string, output);
+    }
+
+    // And a few larger bodies using every part
+    for body_string in ["5T5A5R5H5W5C5L15M", "5TAH5RMH", "2L3W4C5M6A7T8R", "24CM2H"] {
+        let body = body_generation::generate_body_from_string(body_string).unwrap();
+        let output = body_generation::generate_string_from_body(&body);
+        assert!(output.chars().all(|c| c.is_ascii_digit() || known_characters.contains(&c.to_string().as_str())));
+        assert_eq!(body, body_generation::generate_body_from_string(&output).unwrap(), "Input: {}, Output: {}", body_string, output);
+
+        let bodyspec = body_generation::generate_bodyspec_from_string(body_string).unwrap();
+        assert_eq!(output, body_generation::generate_string_from_bodyspec(&bodyspec));
+    }
+}
+
+#[test]
+fn boost_annotations_apply_resource_boosts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("10W:XGH2O 5M:XZHO2").unwrap();
+    let parts = bodyspec.part_specs();
+    assert_eq!(15, parts.len());
+    for p in &parts[..10] {
+        assert_eq!(PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3UpgradeController), *p);
+    }
+    for p in &parts[10..] {
+        assert_eq!(PartSpec::new_boosted_part(Part::Move, AbstractBoost::T3Move), *p);
+    }
+
+    // Annotations at the end of the string don't need trailing whitespace, and any whitespace
+    // can be used to terminate an annotation
+    let bodyspec = body_generation::generate_bodyspec_from_string("2T:GO\t2M:ZO").unwrap();
+    let boosts: Vec<Option<AbstractBoost>> = bodyspec.part_specs().iter().map(|p| p.boost).collect();
+    assert_eq!(vec![Some(AbstractBoost::T1Tough), Some(AbstractBoost::T1Tough), Some(AbstractBoost::T1Move), Some(AbstractBoost::T1Move)], boosts);
+}
+
+#[test]
+fn boost_annotations_apply_named_boosts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("6W[T3Harvest]3M[T2Move]").unwrap();
+    let parts = bodyspec.part_specs();
+    assert_eq!(9, parts.len());
+    assert!(parts[..6].iter().all(|p| p.boost == Some(AbstractBoost::T3Harvest)));
+    assert!(parts[6..].iter().all(|p| p.boost == Some(AbstractBoost::T2Move)));
+
+    // Every boost name is accepted
+    for boost in crate::boost::T1_BOOSTS.into_iter().chain(crate::boost::T2_BOOSTS).chain(crate::boost::T3_BOOSTS) {
+        let part_char = body_generation::convert_part_to_character(&boost.associated_part()).unwrap();
+        let body_string = format!("2{}[{:?}]", part_char, boost);
+        let bodyspec = body_generation::generate_bodyspec_from_string(&body_string).unwrap();
+        assert!(bodyspec.part_specs().iter().all(|p| p.boost == Some(boost)), "Body string: {}", body_string);
+
+        let body_string = format!("2{}:{}", part_char, boost.associated_resource());
+        let bodyspec = body_generation::generate_bodyspec_from_string(&body_string).unwrap();
+        assert!(bodyspec.part_specs().iter().all(|p| p.boost == Some(boost)), "Body string: {}", body_string);
+    }
+}
+
+#[test]
+fn boost_annotations_only_apply_to_matching_parts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("5HM:XLHO2").unwrap();
+    for pair in bodyspec.part_specs().chunks(2) {
+        assert_eq!(PartSpec::new_boosted_part(Part::Heal, AbstractBoost::T3Heal), pair[0]);
+        assert_eq!(PartSpec::new_unboosted_part(Part::Move), pair[1]);
+    }
+
+    // An annotation ends its group, so following parts are unboosted
+    let bodyspec = body_generation::generate_bodyspec_from_string("2H[T3Heal]H").unwrap();
+    let boosts: Vec<Option<AbstractBoost>> = bodyspec.part_specs().iter().map(|p| p.boost).collect();
+    assert_eq!(vec![Some(AbstractBoost::T3Heal), Some(AbstractBoost::T3Heal), None], boosts);
+}
+
+#[test]
+fn boost_annotations_return_error_for_invalid_boosts() {
+    let invalid_strings = [
+        "5M[T3Harvest]",
+        "5M:XGH2O",
+        "5W:energy",
+        "5W:XYZ",
+        "5W:",
+        "5W[]",
+        "5W[T4Harvest]",
+        "5W[T3Harvest",
+        "5:XGH2O",
+        "[T3Move]",
+        "2M[T3Move]:XZHO2",
+    ];
+
+    for s in invalid_strings {
+        let result = body_generation::generate_bodyspec_from_string(s);
+        assert_eq!(Err(body_generation::GenerateBodyError::InvalidBoost), result, "Body string: {}", s);
+    }
+}
+
+#[test]
+fn boost_annotations_are_ignored_when_generating_parts() {
+    let body = body_generation::generate_body_from_string("10W:XGH2O 5M[T3Move]").unwrap();
+    let expected = body_generation::generate_body_from_string("10W5M").unwrap();
+    assert_eq!(expected, body);
+}
+
+#[test]
+fn bodyspec_to_string_includes_boosts() {
+    let test_data = [
+        ("10W:XGH2O 5M:XZHO2", "10W:XGH2O 5M:XZHO2"),
+        ("10W[T3UpgradeController]5M[T3Move]", "10W:XGH2O 5M:XZHO2"),
+        ("5HM:XLHO2", "5HM:XLHO2"),
+        ("2T:XGHO2 2T 4M", "2T:XGHO2 2T4M"),
+        ("W:UO", "W:UO"),
+        ("W:UO W:UO 2M", "WWMM:UO"),
+    ];
+
+    for (input, expected) in test_data {
+        let bodyspec = body_generation::generate_bodyspec_from_string(input).unwrap();
+        let output = body_generation::generate_string_from_bodyspec(&bodyspec);
+        assert_eq!(expected, output, "Input: {}", input);
+        assert_eq!(bodyspec, body_generation::generate_bodyspec_from_string(&output).unwrap(), "Input: {}", input);
+    }
+
+    // Boosts that don't apply to their part can't be represented, and are left out
+    let bodyspec = BodySpec::new(&[PartSpec::new_boosted_part(Part::Move, AbstractBoost::T3Heal)]);
+    assert_eq!("M", body_generation::generate_string_from_bodyspec(&bodyspec));
+}
+
+#[test]
+fn bodyspec_to_string_leaves_off_prefixes_after_boost_annotations() {
+    let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3UpgradeController);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    let w = PartSpec::new_unboosted_part(Part::Work);
+    let bodyspec = BodySpec::new(&[t3_w, m, w]);
+
+    // The whitespace ending the annotation already separates the groups, so "W:XGH2O 1MW" is
+    // longer than it needs to be
+    let output = body_generation::generate_string_from_bodyspec(&bodyspec);
+    assert_eq!("W:XGH2O MW", output);
+    assert_eq!(bodyspec, body_generation::generate_bodyspec_from_string(&output).unwrap());
+
+    // Groups that repeat still use a numeric prefix
+    let bodyspec = body_generation::generate_bodyspec_from_string("W:XGH2O 2M 3C").unwrap();
+    let output = body_generation::generate_string_from_bodyspec(&bodyspec);
+    assert_eq!("W:XGH2O 2M3C", output);
+    assert_eq!(bodyspec, body_generation::generate_bodyspec_from_string(&output).unwrap());
+}
+
+#[test]
+fn oversized_multipliers_return_error_without_expanding() {
+    let result = body_generation::generate_body_from_string("1000000000000W");
+    assert_eq!(Err(body_generation::GenerateBodyError::TooManyBodyParts), result);
+
+    let result = body_generation::generate_body_from_string("99999999999999999999999W");
+    assert_eq!(Err(body_generation::GenerateBodyError::InvalidMultiplier), result);
+}
+
+#[test]
+fn parenthesized_groups_repeat_their_contents() {
+    let test_data = [
+        ("3(2C M)", "CCMCCMCCM"),
+        ("5T 2(10R 5H) 10M", "5T10R5H10R5H10M"),
+        ("2(R)H", "RRH"),
+        ("2MC(3R)", "MCMCRRR"),
+        ("(WM)", "WM"),
+        ("2(2(CM) W)", "CMCMWCMCMW"),
+        ("2(2C M)", "CCMCCM"),
+        ("2 C M", "CCM"),
+        ("2()W", "W"),
+        ("0(W)M", "M"),
+        ("2 (R)", "RR"),
+    ];
+
+    for (input, expected) in test_data {
+        let body = body_generation::generate_body_from_string(input).unwrap();
+        let expected_body = body_generation::generate_body_from_string(expected).unwrap();
+        assert_eq!(expected_body, body, "Input: {}", input);
+    }
+}
+
+#[test]
+fn parenthesized_groups_support_boosts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2(T:XGHO2 R[T3RangedAttack])5M:XZHO2").unwrap();
+    let expected = body_generation::generate_bodyspec_from_string("T:XGHO2 R:XKHO2 T:XGHO2 R:XKHO2 5M:XZHO2").unwrap();
+    assert_eq!(expected, bodyspec);
+
+    // Annotations on a parenthesized group itself aren't supported
+    let result = body_generation::generate_bodyspec_from_string("2(RH):XKHO2");
+    assert_eq!(Err(body_generation::GenerateBodyError::InvalidBoost), result);
+}
+
+#[test]
+fn parenthesized_groups_return_errors() {
+    let test_data = [
+        ("2(RH", body_generation::GenerateBodyError::InvalidString),
+        ("RH)", body_generation::GenerateBodyError::InvalidString),
+        ("(2(R)", body_generation::GenerateBodyError::InvalidString),
+        ("2(10R 5H) 30M", body_generation::GenerateBodyError::TooManyBodyParts),
+        ("5(5(5R))", body_generation::GenerateBodyError::TooManyBodyParts),
+        ("99999999999999999999999(W)", body_generation::GenerateBodyError::InvalidMultiplier),
+    ];
+
+    for (input, expected) in test_data {
+        let result = body_generation::generate_body_from_string(input);
+        assert_eq!(Err(expected), result, "Input: {}", input);
+    }
+
+    // A huge multiplier on an empty group doesn't produce any parts
+    assert_eq!(Ok(Vec::new()), body_generation::generate_body_from_string("1000000000000()"));
+}
+
+#[test]
+fn parse_part_groups_keeps_boost_annotations_with_their_group() {
+    let to_groups = |groups: &[(&str, &str)]| -> Vec<(String, String)> {
+        groups.iter().map(|(m, p)| (m.to_string(), p.to_string())).collect()
+    };
+
+    assert_eq!(to_groups(&[("", "W:XGH2O"), ("2", "M")]), body_generation::parse_part_groups("W:XGH2O 2M"));
+    assert_eq!(to_groups(&[("10", "W:XGH2O"), ("5", "M:XZHO2")]), body_generation::parse_part_groups("10W:XGH2O 5M:XZHO2"));
+    assert_eq!(to_groups(&[("2", "H[T3Heal]"), ("", "H")]), body_generation::parse_part_groups("2H[T3Heal]H"));
+    assert_eq!(to_groups(&[("", "T:XGHO2"), ("", "R[T3RangedAttack]"), ("", "T:XGHO2"), ("", "R[T3RangedAttack]"), ("5", "M")]), body_generation::parse_part_groups("2(T:XGHO2 R[T3RangedAttack])5M"));
+}
+
+#[test]
+fn lenient_parsing_differs_from_previous_versions() {
+    // Each input along with the parts it produces now, and the parts it used to produce before
+    // whitespace, parentheses and boost annotations were part of the grammar
+    let test_data = [
+        ("2W M", "WWM", "WMWM"),
+        ("2 C M", "CCM", "CMCM"),
+        ("3C 2M A", "CCCMMA", "CCCMAMA"),
+        ("2(W)M", "WWM", "WMWM"),
+        ("2()W", "W", "WW"),
+    ];
+
+    for (input, expected, previous) in test_data {
+        let body = body_generation::generate_body_from_string(input).unwrap();
+        assert_eq!(body_generation::generate_body_from_string(expected).unwrap(), body, "Input: {}", input);
+        assert_ne!(body_generation::generate_body_from_string(previous).unwrap(), body, "Input: {}", input);
+    }
+
+    // Unbalanced parentheses and invalid boost annotations used to be ignored, but are now errors
+    let test_data = [
+        ("2(RH", body_generation::GenerateBodyError::InvalidString),
+        ("RH)", body_generation::GenerateBodyError::InvalidString),
+        ("5W:energy", body_generation::GenerateBodyError::InvalidBoost),
+        ("5W[Work]", body_generation::GenerateBodyError::InvalidBoost),
+        ("5M[T3Harvest]", body_generation::GenerateBodyError::InvalidBoost),
+    ];
+
+    for (input, expected) in test_data {
+        assert_eq!(Err(expected), body_generation::generate_body_from_string(input), "Input: {}", input);
+    }
+
+    // Valid annotations used to be read as part characters and multipliers, so "10W:XGH2O" gave
+    // 10 Work and 10 Heal parts, but now it's 10 boosted Work parts
+    let body = body_generation::generate_body_from_string("10W:XGH2O").unwrap();
+    assert_eq!(vec![Part::Work; 10], body);
+}
+
+#[test]
+fn parse_part_groups_flattens_parenthesized_groups() {
+    let to_groups = |groups: &[(&str, &str)]| -> Vec<(String, String)> {
+        groups.iter().map(|(m, p)| (m.to_string(), p.to_string())).collect()
+    };
+
+    assert_eq!(to_groups(&[("", "C"), ("", "C"), ("", "C")]), body_generation::parse_part_groups("3(C)"));
+    assert_eq!(to_groups(&[("2", "C"), ("", "M"), ("2", "C"), ("", "M")]), body_generation::parse_part_groups("2(2C M)"));
+    assert_eq!(to_groups(&[("", "W"), ("", "W"), ("", "M"), ("", "W"), ("", "W"), ("", "M")]), body_generation::parse_part_groups("2(2(W)M)"));
+
+    // Unbalanced parentheses are ignored
+    assert_eq!(to_groups(&[("2", "R"), ("", "H")]), body_generation::parse_part_groups("(2R)H)"));
+    assert_eq!(to_groups(&[("2", "R"), ("", "H")]), body_generation::parse_part_groups("((2R(H"));
+
+    // Repetition is limited to the largest possible body
+    assert_eq!(MAX_CREEP_SIZE as usize, body_generation::parse_part_groups("1000000000000(W)").len());
+    assert_eq!(MAX_CREEP_SIZE as usize, body_generation::parse_part_groups("99999999999999999999999(W)").len());
+    assert_eq!(MAX_CREEP_SIZE as usize, body_generation::parse_part_groups("50(50(50(50(50(W)))))").len());
+    assert!(body_generation::parse_part_groups("1000000000000()").is_empty());
+}
+
+#[test]
+fn strict_parsing_matches_lenient_parsing_for_valid_strings() {
+    let valid_strings = [
+        "WM",
+        "6W3M",
+        "3R3H6M1RHM",
+        "10W:XGH2O 5M:XZHO2",
+        "6W[T3Harvest]3CM",
+        "5T 2(10R 5H) 10M",
+        "2 (R)",
+        "2(2(CM) W)",
+        "2()W",
+        "",
+    ];
+
+    for s in valid_strings {
+        let strict = body_generation::generate_bodyspec_from_string_strict(s);
+        let lenient = body_generation::generate_bodyspec_from_string(s);
+        assert_eq!(lenient, Ok(strict.unwrap()), "Body string: {}", s);
+    }
+}
+
+#[test]
+fn strict_parsing_reports_unexpected_characters() {
+    use body_generation::BodyStringToken as Token;
+
+    let test_data = [
+        ("6W3X", 'X', 3, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("WMé3X", 'é', 2, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis, Token::BoostAnnotation]),
+        ("W é", 'é', 2, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("2 3W", '3', 2, vec![Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        (")", ')', 0, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("(2)", ')', 2, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("2(RX)", 'X', 3, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis, Token::CloseParenthesis, Token::BoostAnnotation]),
+    ];
+
+    for (input, character, offset, expected) in test_data {
+        let result = body_generation::generate_body_from_string_strict(input);
+        let expected_error = body_generation::BodyStringParseError::UnexpectedCharacter { character, offset, expected };
+        assert_eq!(Err(expected_error), result, "Body string: {}", input);
+    }
+
+    // The lenient parser ignores these mistakes, other than unbalanced parentheses
+    assert_eq!(Ok(vec![Part::Work, Part::Work, Part::Work]), body_generation::generate_body_from_string("2 3W"));
+    assert_eq!(Ok(Vec::new()), body_generation::generate_body_from_string("(2)"));
+    assert_eq!(Err(body_generation::GenerateBodyError::InvalidString), body_generation::generate_body_from_string(")"));
+}
+
+#[test]
+fn strict_parsing_reports_unexpected_end() {
+    use body_generation::BodyStringToken as Token;
+
+    let test_data = [
+        ("5T2", 3, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("5T2 ", 4, vec![Token::Part, Token::Whitespace, Token::OpenParenthesis]),
+        ("2(R", 3, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis, Token::CloseParenthesis, Token::BoostAnnotation]),
+        ("2(R ", 4, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis, Token::CloseParenthesis]),
+        ("((R)", 4, vec![Token::Digit, Token::Part, Token::Whitespace, Token::OpenParenthesis, Token::CloseParenthesis]),
+    ];
+
+    for (input, offset, expected) in test_data {
+        let result = body_generation::generate_body_from_string_strict(input);
+        let expected_error = body_generation::BodyStringParseError::UnexpectedEnd { offset, expected };
+        assert_eq!(Err(expected_error), result, "Body string: {}", input);
+    }
+}
+
+#[test]
+fn strict_parsing_reports_invalid_multipliers_and_boosts() {
+    let test_data = [
+        ("W 99999999999999999999999M", body_generation::BodyStringParseError::InvalidMultiplier { offset: 2 }),
+        ("99999999999999999999999(W)", body_generation::BodyStringParseError::InvalidMultiplier { offset: 0 }),
+        ("5M[T3Harvest]", body_generation::BodyStringParseError::InvalidBoost { offset: 2 }),
+        ("10W:energy", body_generation::BodyStringParseError::InvalidBoost { offset: 3 }),
+        ("2(M:XZHO2 W:XZHO2)", body_generation::BodyStringParseError::InvalidBoost { offset: 11 }),
+    ];
+
+    for (input, expected) in test_data {
+        let result = body_generation::generate_body_from_string_strict(input);
+        assert_eq!(Err(expected), result, "Body string: {}", input);
+    }
+}
+
+#[test]
+fn strict_parsing_reports_actual_part_count() {
+    let test_data = [
+        ("50W M", 51),
+        ("2(20R 10H)", 60),
+        ("1000000000000W", 1000000000000),
+        ("99999999999999(99999999999999(WM))", usize::MAX),
+    ];
+
+    for (input, actual) in test_data {
+        let result = body_generation::generate_body_from_string_strict(input);
+        assert_eq!(Err(body_generation::BodyStringParseError::TooManyBodyParts { actual }), result, "Body string: {}", input);
+
+        let result = body_generation::generate_body_from_string(input);
+        assert_eq!(Err(body_generation::GenerateBodyError::TooManyBodyParts), result, "Body string: {}", input);
+    }
+}
+
+#[test]
+fn strict_parse_errors_describe_the_problem() {
+    let error = body_generation::generate_body_from_string_strict("6W3X").unwrap_err();
+    assert_eq!("Unexpected character 'X' at offset 3, expected one of: digit, part character, whitespace, '('", error.to_string());
+
+    let error = body_generation::generate_body_from_string_strict("2(R").unwrap_err();
+    assert_eq!("Unexpected end of string at offset 3, expected one of: digit, part character, whitespace, '(', ')', boost annotation", error.to_string());
+
+    let error = body_generation::generate_body_from_string_strict("60W").unwrap_err();
+    assert_eq!("Too many parts specified: 60 parts, but the maximum is 50", error.to_string());
+    assert_eq!(body_generation::GenerateBodyError::TooManyBodyParts, body_generation::GenerateBodyError::from(error));
+}
@@ -0,0 +1,183 @@
+use screeps::Part;
+
+use crate::boost::boost::BoostCategory;
+use super::body_calculations::PartsSummary;
+use super::{BodySpec, PartSpec};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
+/// Represents what a creep body should be ordered for.
+///
+/// Since the engine destroys body parts front-to-back as a creep takes damage, the order of the
+/// parts in a body determines which capabilities a creep loses first.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BodyOrderingGoal {
+    /// Tough parts first to soak up damage, then the remaining parts grouped by type, with Move
+    /// and Heal parts last so that the creep can keep retreating and healing for as long as
+    /// possible.
+    Combat,
+
+    /// Move parts spread evenly between the other parts, so that losing the front of the body
+    /// doesn't leave the creep with a pile of parts it can no longer move.
+    Hauler,
+}
+
+/// Determines where a part goes relative to other parts when ordering a body.
+///
+/// Lower ranks go towards the front of the body, and will be destroyed first.
+const fn part_rank(part: &Part) -> u8 {
+    match part {
+        Part::Tough => 0,
+        Part::Work => 1,
+        Part::Carry => 2,
+        Part::Claim => 3,
+        Part::Attack => 4,
+        Part::RangedAttack => 5,
+        Part::Move => 6,
+        Part::Heal => 7,
+        _ => 8,
+    }
+}
+
+/// Determines where a part goes relative to other parts of the same type when ordering a body.
+///
+/// Boosted Tough parts go in front of unboosted ones, with the highest tier first, since they're
+/// the ones that reduce damage. For every other part type, the highest tier boosts go at the back
+/// where they're best protected.
+const fn boost_rank(part_spec: &PartSpec) -> u8 {
+    let tier = match part_spec.boost {
+        Some(boost) => boost.tier(),
+        None => 0,
+    };
+
+    match part_spec.part {
+        Part::Tough => 3 - tier,
+        _ => tier,
+    }
+}
+
+/// Orders the provided parts into a body that is tuned for the specified goal.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::PartSpec;
+/// use screeps_body_utils::body::body_ordering::{BodyOrderingGoal, order_body};
+/// use screeps_body_utils::boost::AbstractBoost;
+///
+/// let h = PartSpec::new_unboosted_part(Part::Heal);
+/// let m = PartSpec::new_unboosted_part(Part::Move);
+/// let r = PartSpec::new_unboosted_part(Part::RangedAttack);
+/// let t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+///
+/// // Combat bodies put Tough parts first, and Move and Heal parts last
+/// let bodyspec = order_body(&[h, m, r, t, m, m], BodyOrderingGoal::Combat);
+/// let expected = vec![Part::Tough, Part::RangedAttack, Part::Move, Part::Move, Part::Move, Part::Heal];
+/// assert_eq!(expected, bodyspec.get_parts());
+///
+/// // Hauler bodies spread the Move parts out
+/// let c = PartSpec::new_unboosted_part(Part::Carry);
+/// let bodyspec = order_body(&[c, c, c, c, m, m], BodyOrderingGoal::Hauler);
+/// let expected = vec![Part::Carry, Part::Carry, Part::Move, Part::Carry, Part::Carry, Part::Move];
+/// assert_eq!(expected, bodyspec.get_parts());
+/// ```
+pub fn order_body(parts: &[PartSpec], goal: BodyOrderingGoal) -> BodySpec {
+    let mut sorted_parts = parts.to_vec();
+    sorted_parts.sort_by_key(|p| (part_rank(&p.part), boost_rank(p)));
+
+    match goal {
+        BodyOrderingGoal::Combat => BodySpec::raw_new(sorted_parts),
+        BodyOrderingGoal::Hauler => {
+            let (move_parts, non_move_parts): (Vec<PartSpec>, Vec<PartSpec>) = sorted_parts.into_iter().partition(|p| p.part == Part::Move);
+            let num_move_parts = move_parts.len();
+            let num_non_move_parts = non_move_parts.len();
+
+            let mut body = Vec::with_capacity(num_move_parts + num_non_move_parts);
+            let mut move_iter = move_parts.into_iter();
+            let mut moves_placed = 0;
+            for (i, p) in non_move_parts.into_iter().enumerate() {
+                body.push(p);
+
+                // Place Move parts so that they're spread proportionally across the body
+                let moves_wanted = (i + 1) * num_move_parts / num_non_move_parts;
+                while moves_placed < moves_wanted {
+                    // Unwrap is safe since moves_wanted never exceeds the number of Move parts
+                    body.push(move_iter.next().unwrap());
+                    moves_placed += 1;
+                }
+            }
+
+            // Only happens if there were no non-Move parts
+            body.extend(move_iter);
+            BodySpec::raw_new(body)
+        },
+    }
+}
+
+/// Converts a [PartsSummary] for a particular [BoostCategory] into the individual parts it
+/// describes.
+///
+/// Unboosted parts come first, followed by the T1, T2, and T3 boosted parts.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_calculations::PartsSummary;
+/// use screeps_body_utils::body::body_ordering::part_specs_from_summary;
+/// use screeps_body_utils::boost::AbstractBoost;
+/// use screeps_body_utils::boost::boost::BoostCategory;
+///
+/// let summary = PartsSummary::checked_new(3, 0, 0, 2).unwrap();
+/// let parts = part_specs_from_summary(&BoostCategory::Heal, &summary);
+/// assert_eq!(3, parts.len());
+/// assert!(parts.iter().all(|p| p.part == Part::Heal));
+/// assert_eq!(None, parts[0].boost);
+/// assert_eq!(Some(AbstractBoost::T3Heal), parts[1].boost);
+/// assert_eq!(Some(AbstractBoost::T3Heal), parts[2].boost);
+/// ```
+pub fn part_specs_from_summary(category: &BoostCategory, summary: &PartsSummary) -> Vec<PartSpec> {
+    let part = category.get_associated_part();
+    let [t1_boost, t2_boost, t3_boost] = category.get_abstract_boosts();
+
+    let mut parts = Vec::with_capacity(summary.num_parts());
+    parts.extend(std::iter::repeat_n(PartSpec::new_unboosted_part(part), summary.num_unboosted_parts()));
+    parts.extend(std::iter::repeat_n(PartSpec::new_boosted_part(part, t1_boost), summary.num_t1_parts()));
+    parts.extend(std::iter::repeat_n(PartSpec::new_boosted_part(part, t2_boost), summary.num_t2_parts()));
+    parts.extend(std::iter::repeat_n(PartSpec::new_boosted_part(part, t3_boost), summary.num_t3_parts()));
+    parts
+}
+
+/// Orders the parts described by a set of [PartsSummary] values into a body that is tuned for
+/// the specified goal.
+///
+/// This is a convenience wrapper around [part_specs_from_summary] and [order_body], useful for
+/// turning the output of the [parts_to_*](crate::body::body_calculations) helpers into a body.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_calculations::{BoostSelectionConfig, BoostTierChoice, parts_to_heal, parts_to_ranged_attack, parts_to_move_offroad_by_parts_count};
+/// use screeps_body_utils::body::body_ordering::{BodyOrderingGoal, order_body_from_summaries};
+/// use screeps_body_utils::boost::boost::BoostCategory;
+///
+/// let boost_config = BoostSelectionConfig::new(BoostTierChoice::NoBoosts, false);
+/// let ranged = parts_to_ranged_attack(30, &boost_config).unwrap();
+/// let heal = parts_to_heal(24, &boost_config).unwrap();
+/// let moves = parts_to_move_offroad_by_parts_count(5, &boost_config).unwrap();
+///
+/// let summaries = [
+///     (BoostCategory::Heal, heal),
+///     (BoostCategory::RangedAttack, ranged),
+///     (BoostCategory::Move, moves),
+/// ];
+/// let bodyspec = order_body_from_summaries(&summaries, BodyOrderingGoal::Combat);
+/// assert_eq!(10, bodyspec.get_parts().len());
+/// assert_eq!(Part::RangedAttack, bodyspec.get_parts()[0]);
+/// assert_eq!(Part::Heal, bodyspec.get_parts()[9]);
+/// ```
+pub fn order_body_from_summaries(summaries: &[(BoostCategory, PartsSummary)], goal: BodyOrderingGoal) -> BodySpec {
+    let parts: Vec<PartSpec> = summaries.iter()
+        .flat_map(|(category, summary)| part_specs_from_summary(category, summary))
+        .collect();
+    order_body(&parts, goal)
+}
@@ -0,0 +1,140 @@
+use screeps::Part;
+
+use super::body_calculations::PartsSummary;
+use super::body_generation;
+use super::body_ordering::{BodyOrderingGoal, order_body, order_body_from_summaries, part_specs_from_summary};
+use crate::body::{BodySpec, PartSpec};
+use crate::boost::AbstractBoost;
+use crate::boost::boost::BoostCategory;
+
+
+const GOALS: [BodyOrderingGoal; 2] = [
+    BodyOrderingGoal::Combat,
+    BodyOrderingGoal::Hauler,
+];
+
+fn part_counts(bodyspec: &BodySpec) -> Vec<(Part, Option<AbstractBoost>, usize)> {
+    let mut counts: Vec<(Part, Option<AbstractBoost>, usize)> = Vec::new();
+    for p in bodyspec.part_specs() {
+        match counts.iter_mut().find(|(part, boost, _)| *part == p.part && *boost == p.boost) {
+            Some(entry) => entry.2 += 1,
+            None => counts.push((p.part, p.boost, 1)),
+        }
+    }
+    counts.sort_by_key(|(part, boost, _)| (*part as u8, boost.map(|b| b.associated_resource() as u16)));
+    counts
+}
+
+#[test]
+fn order_body_preserves_parts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("1M2H3R4T5A6W7C1L5M").unwrap();
+    let t3_heal = PartSpec::new_boosted_part(Part::Heal, AbstractBoost::T3Heal);
+    let mut parts = bodyspec.part_specs().to_vec();
+    parts.push(t3_heal);
+    let bodyspec = BodySpec::new(&parts);
+
+    for goal in GOALS {
+        let ordered = order_body(bodyspec.part_specs(), goal);
+        assert_eq!(part_counts(&bodyspec), part_counts(&ordered), "Goal: {:?}", goal);
+    }
+}
+
+#[test]
+fn order_body_handles_empty_and_single_type_bodies() {
+    for goal in GOALS {
+        assert_eq!(BodySpec::new(&[]), order_body(&[], goal));
+
+        for body_str in ["5M", "5C", "1T"] {
+            let bodyspec = body_generation::generate_bodyspec_from_string(body_str).unwrap();
+            assert_eq!(bodyspec, order_body(bodyspec.part_specs(), goal));
+        }
+    }
+}
+
+#[test]
+fn order_body_combat_puts_tough_first_and_heal_last() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2H5M2A1W3T3R").unwrap();
+    let ordered = order_body(bodyspec.part_specs(), BodyOrderingGoal::Combat);
+    let expected = body_generation::generate_body_from_string("3T1W2A3R5M2H").unwrap();
+    assert_eq!(expected, ordered.get_parts());
+}
+
+#[test]
+fn order_body_combat_orders_boosts_within_part_types() {
+    let t = PartSpec::new_unboosted_part(Part::Tough);
+    let t1_t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T1Tough);
+    let t3_t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    let h = PartSpec::new_unboosted_part(Part::Heal);
+    let t2_h = PartSpec::new_boosted_part(Part::Heal, AbstractBoost::T2Heal);
+    let t3_h = PartSpec::new_boosted_part(Part::Heal, AbstractBoost::T3Heal);
+
+    let ordered = order_body(&[t3_h, t, h, t1_t, t2_h, t3_t], BodyOrderingGoal::Combat);
+
+    // The strongest Tough parts absorb damage first, while the strongest Heal parts are protected
+    assert_eq!(&[t3_t, t1_t, t, h, t2_h, t3_h], ordered.part_specs());
+}
+
+#[test]
+fn order_body_combat_improves_survivability() {
+    let t3_t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    let h = PartSpec::new_unboosted_part(Part::Heal);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    let badly_ordered = BodySpec::new(&[h, h, m, m, t3_t, t3_t]);
+    let ordered = order_body(badly_ordered.part_specs(), BodyOrderingGoal::Combat);
+
+    // Damage hits the boosted Tough parts first, so far less of it gets through
+    assert!(ordered.damage_taken(400) < badly_ordered.damage_taken(400));
+    assert_ne!(0, ordered.apply_damage(400).heal_amount());
+    assert_eq!(0, badly_ordered.apply_damage(400).heal_amount());
+}
+
+#[test]
+fn order_body_hauler_interleaves_move_parts() {
+    let test_data = [
+        ("2C2M", "1C1M1C1M"),
+        ("4C2M", "2C1M2C1M"),
+        ("3C1M", "3C1M"),
+        ("1W2C3M", "1W1M1C1M1C1M"),
+        ("2C4M", "1C2M1C2M"),
+    ];
+
+    for (input, expected) in test_data {
+        let bodyspec = body_generation::generate_bodyspec_from_string(input).unwrap();
+        let ordered = order_body(bodyspec.part_specs(), BodyOrderingGoal::Hauler);
+        let expected_parts = body_generation::generate_body_from_string(expected).unwrap();
+        assert_eq!(expected_parts, ordered.get_parts(), "Input: {}", input);
+    }
+}
+
+#[test]
+fn part_specs_from_summary_uses_category_boosts() {
+    let summary = PartsSummary::checked_new(10, 1, 2, 3).unwrap();
+    let parts = part_specs_from_summary(&BoostCategory::HarvestEnergy, &summary);
+    assert_eq!(10, parts.len());
+    assert!(parts.iter().all(|p| p.part == Part::Work && p.is_active()));
+    assert_eq!(4, parts.iter().filter(|p| p.boost.is_none()).count());
+    assert_eq!(1, parts.iter().filter(|p| p.boost == Some(AbstractBoost::T1Harvest)).count());
+    assert_eq!(2, parts.iter().filter(|p| p.boost == Some(AbstractBoost::T2Harvest)).count());
+    assert_eq!(3, parts.iter().filter(|p| p.boost == Some(AbstractBoost::T3Harvest)).count());
+
+    let summary = PartsSummary::checked_new(0, 0, 0, 0).unwrap();
+    assert!(part_specs_from_summary(&BoostCategory::Move, &summary).is_empty());
+}
+
+#[test]
+fn order_body_from_summaries_matches_order_body() {
+    let summaries = [
+        (BoostCategory::Carry, PartsSummary::checked_new(8, 0, 0, 0).unwrap()),
+        (BoostCategory::Move, PartsSummary::checked_new(4, 0, 0, 0).unwrap()),
+        (BoostCategory::Tough, PartsSummary::checked_new(2, 0, 0, 2).unwrap()),
+    ];
+
+    let parts: Vec<PartSpec> = summaries.iter().flat_map(|(c, s)| part_specs_from_summary(c, s)).collect();
+    for goal in GOALS {
+        assert_eq!(order_body(&parts, goal), order_body_from_summaries(&summaries, goal));
+    }
+
+    let ordered = order_body_from_summaries(&summaries, BodyOrderingGoal::Hauler);
+    let expected = body_generation::generate_body_from_string("2T1C1M2C1M3C1M2C1M").unwrap();
+    assert_eq!(expected, ordered.get_parts());
+}
@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt;
+
+use screeps::Part;
+use screeps::constants::MAX_CREEP_SIZE;
+
+use crate::boost::boost::BoostCategory;
+use super::body_calculations::*;
+use super::body_ordering::{BodyOrderingGoal, order_body, part_specs_from_summary};
+use super::{BodySpec, PartSpec};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// Errors that can occur when planning a body for a set of targets.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BodyPlanError {
+    /// The target at the specified index needs more parts than can be put onto a creep body by
+    /// itself
+    TargetTooLarge {
+        index: usize,
+    },
+
+    /// The targets and the Move parts they need add up to more than
+    /// [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE) parts
+    TooManyParts {
+        needed: usize,
+    },
+}
+
+impl fmt::Display for BodyPlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TargetTooLarge { index } => write!(f, "Target {} needs too many parts for a single body", index),
+            Self::TooManyParts { needed } => write!(f, "Targets need {} parts, more than fit in a single body", needed),
+        }
+    }
+}
+
+impl Error for BodyPlanError {}
+
+/// A per-tick capability that a planned body needs to have.
+///
+/// The amounts match the ones taken by the corresponding [parts_to_*](crate::body::body_calculations)
+/// helper.
+///
+/// With the `serde` feature enabled, a CapabilityTarget is represented as a map from the variant
+/// name to the amount (i.e. `{"HarvestEnergy": 10}`).
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CapabilityTarget {
+    /// Energy harvested per tick, as per [parts_to_harvest_energy]
+    HarvestEnergy(u32),
+
+    /// Minerals harvested per tick, as per [parts_to_harvest_mineral]
+    HarvestMineral(u32),
+
+    /// Deposit resources harvested per tick, as per [parts_to_harvest_deposit]
+    HarvestDeposit(u32),
+
+    /// Build progress per tick, as per [parts_to_build]
+    Build(f32),
+
+    /// Hits repaired per tick, as per [parts_to_repair]
+    Repair(f32),
+
+    /// Hits dismantled per tick, as per [parts_to_dismantle]
+    Dismantle(u32),
+
+    /// Upgrade progress per tick, as per [parts_to_upgrade_controller]
+    UpgradeController(f32),
+
+    /// Melee damage per tick, as per [parts_to_attack]
+    Attack(u32),
+
+    /// Ranged damage per tick, as per [parts_to_ranged_attack]
+    RangedAttack(u32),
+
+    /// Ranged mass attack damage per tick, as per [parts_to_ranged_mass_attack]
+    RangedMassAttack(u32),
+
+    /// Hits healed per tick, as per [parts_to_heal]
+    Heal(u32),
+
+    /// Hits healed at range per tick, as per [parts_to_ranged_heal]
+    RangedHeal(u32),
+
+    /// Store capacity, as per [parts_to_carry]
+    Carry(u32),
+
+    /// Damage absorbed, as per [parts_to_absorb_damage]
+    AbsorbDamage(f32),
+
+    /// Reservation ticks per tick, as per [parts_to_reserve_controller]
+    ReserveController(u32),
+}
+
+impl CapabilityTarget {
+    /// Calculates the parts needed to meet this target by itself.
+    fn part_specs(&self, boost_config: &BoostSelectionConfig) -> Result<Vec<PartSpec>, PartsNeededCalculationError> {
+        let (category, summary) = match *self {
+            Self::HarvestEnergy(amount) => (BoostCategory::HarvestEnergy, parts_to_harvest_energy(amount, boost_config)?),
+            Self::HarvestMineral(amount) => (BoostCategory::HarvestMineral, parts_to_harvest_mineral(amount, boost_config)?),
+            Self::HarvestDeposit(amount) => (BoostCategory::HarvestDeposit, parts_to_harvest_deposit(amount, boost_config)?),
+            Self::Build(amount) => (BoostCategory::Build, parts_to_build(amount, boost_config)?),
+            Self::Repair(amount) => (BoostCategory::Repair, parts_to_repair(amount, boost_config)?),
+            Self::Dismantle(amount) => (BoostCategory::Dismantle, parts_to_dismantle(amount, boost_config)?),
+            Self::UpgradeController(amount) => (BoostCategory::UpgradeController, parts_to_upgrade_controller(amount, boost_config)?),
+            Self::Attack(amount) => (BoostCategory::Attack, parts_to_attack(amount, boost_config)?),
+            Self::RangedAttack(amount) => (BoostCategory::RangedAttack, parts_to_ranged_attack(amount, boost_config)?),
+            Self::RangedMassAttack(amount) => (BoostCategory::RangedMassAttack, parts_to_ranged_mass_attack(amount, boost_config)?),
+            Self::Heal(amount) => (BoostCategory::Heal, parts_to_heal(amount, boost_config)?),
+            Self::RangedHeal(amount) => (BoostCategory::RangedHeal, parts_to_ranged_heal(amount, boost_config)?),
+            Self::Carry(amount) => (BoostCategory::Carry, parts_to_carry(amount, boost_config)?),
+            Self::AbsorbDamage(amount) => (BoostCategory::Tough, parts_to_absorb_damage(amount, boost_config)?),
+            Self::ReserveController(amount) => {
+                // Claim parts can't be boosted, so they have no boost category
+                let summary = parts_to_reserve_controller(amount)?;
+                return Ok(vec![PartSpec::new_unboosted_part(Part::Claim); summary.num_parts()]);
+            },
+        };
+
+        Ok(part_specs_from_summary(&category, &summary))
+    }
+}
+
+/// The terrain a planned body needs to be able to move across without generating fatigue every
+/// tick.
+///
+/// With the `serde` feature enabled, a MovementTarget is represented as the variant name.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MovementTarget {
+    /// No Move parts are added
+    Stationary,
+
+    /// Enough Move parts to move along roads, as per [parts_to_move_onroad_by_parts_count]
+    OnRoad,
+
+    /// Enough Move parts to move across plains, as per [parts_to_move_offroad_by_parts_count]
+    OffRoad,
+}
+
+/// Plans a complete body that meets all of the specified targets, along with the Move parts
+/// needed to move it across the specified terrain.
+///
+/// Each target is sized independently with the boost configuration, and the resulting parts are
+/// added together; parts are never shared between targets, so `Heal` and `RangedHeal` targets
+/// each get their own Heal parts. The Move parts use the same boost configuration, so boosted
+/// Move parts are accounted for.
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::body::body_calculations::BoostSelectionConfig;
+/// use screeps_body_utils::body::body_ordering::BodyOrderingGoal;
+/// use screeps_body_utils::body::body_planning::{CapabilityTarget, MovementTarget, plan_body};
+///
+/// // A remote miner that harvests 10 energy per tick and carries 100 energy
+/// let targets = [CapabilityTarget::HarvestEnergy(10), CapabilityTarget::Carry(100)];
+/// let boost_config = BoostSelectionConfig::default();
+/// let bodyspec = plan_body(&targets, MovementTarget::OffRoad, &boost_config, BodyOrderingGoal::Hauler).unwrap();
+///
+/// let parts = bodyspec.get_parts();
+/// assert_eq!(5, parts.iter().filter(|p| **p == Part::Work).count());
+/// assert_eq!(2, parts.iter().filter(|p| **p == Part::Carry).count());
+/// assert_eq!(7, parts.iter().filter(|p| **p == Part::Move).count());
+/// ```
+pub fn plan_body(targets: &[CapabilityTarget], movement: MovementTarget, boost_config: &BoostSelectionConfig, goal: BodyOrderingGoal) -> Result<BodySpec, BodyPlanError> {
+    let mut parts = Vec::new();
+    for (index, target) in targets.iter().enumerate() {
+        let target_parts = target.part_specs(boost_config).map_err(|_| BodyPlanError::TargetTooLarge { index })?;
+        parts.extend(target_parts);
+    }
+
+    let num_non_move_parts = parts.len();
+    if num_non_move_parts > MAX_CREEP_SIZE as usize {
+        return Err(BodyPlanError::TooManyParts { needed: num_non_move_parts });
+    }
+
+    let move_summary = match movement {
+        MovementTarget::Stationary => None,
+        MovementTarget::OnRoad => Some(parts_to_move_onroad_by_parts_count(num_non_move_parts as u32, boost_config)),
+        MovementTarget::OffRoad => Some(parts_to_move_offroad_by_parts_count(num_non_move_parts as u32, boost_config)),
+    };
+
+    if let Some(move_summary) = move_summary {
+        // At most one Move part is needed per non-Move part, so this can't fail once the non-Move
+        // parts fit, but report the worst case regardless
+        let move_summary = move_summary.map_err(|_| BodyPlanError::TooManyParts { needed: num_non_move_parts * 2 })?;
+        parts.extend(part_specs_from_summary(&BoostCategory::Move, &move_summary));
+    }
+
+    if parts.len() > MAX_CREEP_SIZE as usize {
+        return Err(BodyPlanError::TooManyParts { needed: parts.len() });
+    }
+
+    Ok(order_body(&parts, goal))
+}
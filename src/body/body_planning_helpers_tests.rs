@@ -0,0 +1,90 @@
+use screeps::Part;
+
+use super::body_calculations::{BoostSelectionConfig, BoostTierChoice};
+use super::body_generation;
+use super::body_ordering::{BodyOrderingGoal, order_body};
+use super::body_planning::{BodyPlanError, CapabilityTarget, MovementTarget, plan_body};
+use crate::body::BodySpec;
+
+
+fn ordered_bodyspec(body_string: &str, goal: BodyOrderingGoal) -> BodySpec {
+    let bodyspec = body_generation::generate_bodyspec_from_string(body_string).unwrap();
+    order_body(bodyspec.part_specs(), goal)
+}
+
+#[test]
+fn plan_body_adds_move_parts_for_terrain() {
+    let targets = [CapabilityTarget::HarvestEnergy(10), CapabilityTarget::Carry(100)];
+    let boost_config = BoostSelectionConfig::default();
+    let goal = BodyOrderingGoal::Hauler;
+    let test_data = [
+        (MovementTarget::Stationary, "5W2C"),
+        (MovementTarget::OnRoad, "5W2C4M"),
+        (MovementTarget::OffRoad, "5W2C7M"),
+    ];
+
+    for (movement, expected) in test_data {
+        let result = plan_body(&targets, movement, &boost_config, goal).unwrap();
+        assert_eq!(ordered_bodyspec(expected, goal), result, "Movement: {:?}", movement);
+    }
+}
+
+#[test]
+fn plan_body_boosts_move_parts() {
+    let targets = [CapabilityTarget::HarvestEnergy(10), CapabilityTarget::Carry(100)];
+    let boost_config = BoostSelectionConfig::new(BoostTierChoice::T3Only, false);
+    let goal = BodyOrderingGoal::Hauler;
+
+    let result = plan_body(&targets, MovementTarget::OffRoad, &boost_config, goal).unwrap();
+    let expected = ordered_bodyspec("W[T3Harvest] C[T3Carry] M[T3Move]", goal);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn plan_body_combines_every_target() {
+    let targets = [
+        CapabilityTarget::AbsorbDamage(200.0),
+        CapabilityTarget::RangedAttack(20),
+        CapabilityTarget::Heal(24),
+        CapabilityTarget::RangedHeal(8),
+        CapabilityTarget::ReserveController(1),
+    ];
+    let boost_config = BoostSelectionConfig::default();
+    let goal = BodyOrderingGoal::Combat;
+
+    let result = plan_body(&targets, MovementTarget::OffRoad, &boost_config, goal).unwrap();
+    let expected = ordered_bodyspec("2T 2R 2H 2H L 9M", goal);
+    assert_eq!(expected, result);
+    assert_eq!(Part::Tough, result.get_parts()[0]);
+}
+
+#[test]
+fn plan_body_returns_errors_for_oversized_bodies() {
+    let boost_config = BoostSelectionConfig::default();
+    let goal = BodyOrderingGoal::Hauler;
+
+    // Each target fits alone, but not along with their Move parts
+    let targets = [CapabilityTarget::HarvestEnergy(60)];
+    let result = plan_body(&targets, MovementTarget::OffRoad, &boost_config, goal);
+    assert_eq!(Err(BodyPlanError::TooManyParts { needed: 60 }), result);
+
+    let result = plan_body(&targets, MovementTarget::Stationary, &boost_config, goal);
+    assert_eq!(30, result.unwrap().part_specs().len());
+
+    // Each target fits alone, but not together
+    let targets = [CapabilityTarget::Carry(1500), CapabilityTarget::Carry(1500)];
+    let result = plan_body(&targets, MovementTarget::Stationary, &boost_config, goal);
+    assert_eq!(Err(BodyPlanError::TooManyParts { needed: 60 }), result);
+
+    // A single target doesn't fit
+    let targets = [CapabilityTarget::Carry(50), CapabilityTarget::Carry(10000)];
+    let result = plan_body(&targets, MovementTarget::Stationary, &boost_config, goal);
+    assert_eq!(Err(BodyPlanError::TargetTooLarge { index: 1 }), result);
+}
+
+#[test]
+fn plan_body_with_no_targets_is_empty() {
+    let boost_config = BoostSelectionConfig::default();
+    let result = plan_body(&[], MovementTarget::OffRoad, &boost_config, BodyOrderingGoal::Combat).unwrap();
+    assert!(result.part_specs().is_empty());
+}
@@ -0,0 +1,422 @@
+use std::error::Error;
+use std::fmt;
+
+use screeps::constants::MAX_CREEP_SIZE;
+
+use super::body_specification::{GenerateBodyError, generate_bodyspec_from_string};
+use super::{BodySpec, PartSpec};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// Errors that can occur when scaling a [BodyTemplate].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BodyScalingError {
+    /// The template has no parts in its repeating pattern
+    EmptyPattern,
+
+    /// The energy budget can't cover even a single repetition of the pattern
+    InsufficientEnergy,
+
+    /// A single repetition of the pattern would result in more than
+    /// [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE) parts
+    TooManyParts,
+}
+
+impl fmt::Display for BodyScalingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::EmptyPattern => "Body template has an empty pattern",
+            Self::InsufficientEnergy => "Not enough energy for the body template",
+            Self::TooManyParts => "Too many parts in the body template",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Error for BodyScalingError {}
+
+/// Describes a body made up of a fixed prefix, a pattern that repeats as many times as possible,
+/// and a fixed suffix.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_scaling::BodyTemplate;
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+///
+/// // A worker that scales with the available energy
+/// let template = BodyTemplate::from_strings("", "WCM", "").unwrap();
+/// let bodyspec = template.scale(550).unwrap();
+/// assert_eq!(generate_bodyspec_from_string("2WCM").unwrap(), bodyspec);
+///
+/// // A dedicated harvester, with a single Carry part at the end
+/// let template = BodyTemplate::from_strings("", "2W M", "C").unwrap();
+/// let bodyspec = template.scale(800).unwrap();
+/// assert_eq!(generate_bodyspec_from_string("3(2W M) C").unwrap(), bodyspec);
+/// assert_eq!(800, bodyspec.energy_to_spawn());
+/// ```
+///
+/// With the `serde` feature enabled, a BodyTemplate is represented as a map with the keys
+/// `prefix`, `pattern`, and `suffix`, each holding a sequence of [PartSpec]s.
+#[derive(Debug, PartialEq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BodyTemplate {
+    /// Parts that go at the front of the body, before any repetitions of the pattern
+    pub prefix: Vec<PartSpec>,
+
+    /// Parts that are repeated as many times as possible
+    pub pattern: Vec<PartSpec>,
+
+    /// Parts that go at the back of the body, after all repetitions of the pattern
+    pub suffix: Vec<PartSpec>,
+}
+
+impl BodyTemplate {
+    /// Creates a new BodyTemplate. The prefix and suffix can be empty if they're not needed.
+    pub fn new(prefix: &[PartSpec], pattern: &[PartSpec], suffix: &[PartSpec]) -> Self {
+        Self {
+            prefix: prefix.to_vec(),
+            pattern: pattern.to_vec(),
+            suffix: suffix.to_vec(),
+        }
+    }
+
+    /// Creates a new BodyTemplate from body spec strings, as per
+    /// [generate_bodyspec_from_string]. The prefix and suffix can be empty strings if they're not
+    /// needed.
+    pub fn from_strings(prefix: &str, pattern: &str, suffix: &str) -> Result<Self, GenerateBodyError> {
+        Ok(Self {
+            prefix: generate_bodyspec_from_string(prefix)?.part_specs().to_vec(),
+            pattern: generate_bodyspec_from_string(pattern)?.part_specs().to_vec(),
+            suffix: generate_bodyspec_from_string(suffix)?.part_specs().to_vec(),
+        })
+    }
+
+    /// Calculates the largest number of times the pattern can be repeated, given the energy
+    /// budget and [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE).
+    ///
+    /// The prefix and suffix are always included, and the pattern must be repeated at least once.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_scaling::{BodyScalingError, BodyTemplate};
+    ///
+    /// let template = BodyTemplate::from_strings("", "CM", "").unwrap();
+    /// assert_eq!(Ok(1), template.repeats_for_energy(100));
+    /// assert_eq!(Ok(1), template.repeats_for_energy(199));
+    /// assert_eq!(Ok(25), template.repeats_for_energy(10000));
+    /// assert_eq!(Err(BodyScalingError::InsufficientEnergy), template.repeats_for_energy(99));
+    /// ```
+    pub fn repeats_for_energy(&self, energy_budget: u32) -> Result<usize, BodyScalingError> {
+        if self.pattern.is_empty() {
+            return Err(BodyScalingError::EmptyPattern);
+        }
+
+        let fixed_parts = self.prefix.len() + self.suffix.len();
+        let max_parts = MAX_CREEP_SIZE as usize;
+        if fixed_parts + self.pattern.len() > max_parts {
+            return Err(BodyScalingError::TooManyParts);
+        }
+
+        let fixed_cost = parts_energy_cost(&self.prefix) + parts_energy_cost(&self.suffix);
+        let pattern_cost = parts_energy_cost(&self.pattern);
+        if fixed_cost + pattern_cost > energy_budget {
+            return Err(BodyScalingError::InsufficientEnergy);
+        }
+
+        // Pattern cost is non-zero, since every part costs energy and the pattern isn't empty
+        let energy_repeats = ((energy_budget - fixed_cost) / pattern_cost) as usize;
+        let size_repeats = (max_parts - fixed_parts) / self.pattern.len();
+        Ok(energy_repeats.min(size_repeats))
+    }
+
+    /// Builds the largest body that fits within the energy budget and
+    /// [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE).
+    ///
+    /// See [repeats_for_energy](BodyTemplate::repeats_for_energy) for details.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_scaling::BodyTemplate;
+    ///
+    /// // A hauler is capped by the maximum creep size
+    /// let template = BodyTemplate::from_strings("", "2C M", "").unwrap();
+    /// let bodyspec = template.scale(12900).unwrap();
+    /// assert_eq!(48, bodyspec.get_parts().len());
+    /// ```
+    pub fn scale(&self, energy_budget: u32) -> Result<BodySpec, BodyScalingError> {
+        let repeats = self.repeats_for_energy(energy_budget)?;
+        self.with_repeats(repeats)
+    }
+
+    /// Builds the body with the pattern repeated the specified number of times.
+    ///
+    /// Energy isn't checked, but the resulting body can't have more than
+    /// [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE) parts.
+    pub fn with_repeats(&self, repeats: usize) -> Result<BodySpec, BodyScalingError> {
+        let num_parts = self.pattern.len().saturating_mul(repeats).saturating_add(self.prefix.len() + self.suffix.len());
+        if num_parts > MAX_CREEP_SIZE as usize {
+            return Err(BodyScalingError::TooManyParts);
+        }
+
+        let mut body = Vec::with_capacity(num_parts);
+        body.extend_from_slice(&self.prefix);
+        for _ in 0..repeats {
+            body.extend_from_slice(&self.pattern);
+        }
+        body.extend_from_slice(&self.suffix);
+        Ok(BodySpec::raw_new(body))
+    }
+}
+
+/// The energy needed to spawn the parts, as per [energy_to_spawn](BodySpec::energy_to_spawn).
+fn parts_energy_cost(parts: &[PartSpec]) -> u32 {
+    parts.iter().map(|p| p.part.cost()).sum()
+}
+
+/// A capability of a creep body that can be measured with the [BodySpec] metrics.
+///
+/// With the `serde` feature enabled, capabilities are represented by their variant name, i.e.
+/// `"HarvestEnergy"`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BodyCapability {
+    /// Measured with [effective_hits](BodySpec::effective_hits)
+    EffectiveHits,
+
+    /// Measured with [carry_capacity](BodySpec::carry_capacity)
+    CarryCapacity,
+
+    /// The total fatigue reduction of the body's Move parts
+    FatigueReduction,
+
+    /// Measured with [attack_damage](BodySpec::attack_damage)
+    Attack,
+
+    /// Measured with [ranged_attack_damage](BodySpec::ranged_attack_damage)
+    RangedAttack,
+
+    /// Measured with [heal_amount](BodySpec::heal_amount)
+    Heal,
+
+    /// Measured with [ranged_heal_amount](BodySpec::ranged_heal_amount)
+    RangedHeal,
+
+    /// Measured with [harvest_energy_amount](BodySpec::harvest_energy_amount)
+    HarvestEnergy,
+
+    /// Measured with [harvest_mineral_amount](BodySpec::harvest_mineral_amount)
+    HarvestMineral,
+
+    /// Measured with [harvest_deposit_amount](BodySpec::harvest_deposit_amount)
+    HarvestDeposit,
+
+    /// Measured with [build_amount](BodySpec::build_amount)
+    Build,
+
+    /// Measured with [repair_amount](BodySpec::repair_amount)
+    Repair,
+
+    /// Measured with [dismantle_damage](BodySpec::dismantle_damage)
+    Dismantle,
+
+    /// Measured with [upgrade_controller_amount](BodySpec::upgrade_controller_amount)
+    UpgradeController,
+
+    /// Measured with [reserve_controller_amount](BodySpec::reserve_controller_amount)
+    ReserveController,
+}
+
+/// Every [BodyCapability], in declaration order.
+pub const ALL_BODY_CAPABILITIES: [BodyCapability; 15] = [
+    BodyCapability::EffectiveHits,
+    BodyCapability::CarryCapacity,
+    BodyCapability::FatigueReduction,
+    BodyCapability::Attack,
+    BodyCapability::RangedAttack,
+    BodyCapability::Heal,
+    BodyCapability::RangedHeal,
+    BodyCapability::HarvestEnergy,
+    BodyCapability::HarvestMineral,
+    BodyCapability::HarvestDeposit,
+    BodyCapability::Build,
+    BodyCapability::Repair,
+    BodyCapability::Dismantle,
+    BodyCapability::UpgradeController,
+    BodyCapability::ReserveController,
+];
+
+impl BodyCapability {
+    /// Measures this capability for a body.
+    ///
+    /// ```rust
+    /// use screeps::HARVEST_POWER;
+    /// use screeps_body_utils::body::body_scaling::BodyCapability;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    ///
+    /// let bodyspec = generate_bodyspec_from_string("6W3M").unwrap();
+    /// assert_eq!((HARVEST_POWER * 6) as f32, BodyCapability::HarvestEnergy.measure(&bodyspec));
+    /// assert_eq!(0.0, BodyCapability::CarryCapacity.measure(&bodyspec));
+    /// ```
+    pub fn measure(&self, bodyspec: &BodySpec) -> f32 {
+        match self {
+            Self::EffectiveHits => bodyspec.effective_hits() as f32,
+            Self::CarryCapacity => bodyspec.carry_capacity() as f32,
+            Self::FatigueReduction => bodyspec.part_specs().iter().map(|p| p.get_fatigue_reduction()).sum::<u32>() as f32,
+            Self::Attack => bodyspec.attack_damage() as f32,
+            Self::RangedAttack => bodyspec.ranged_attack_damage() as f32,
+            Self::Heal => bodyspec.heal_amount() as f32,
+            Self::RangedHeal => bodyspec.ranged_heal_amount() as f32,
+            Self::HarvestEnergy => bodyspec.harvest_energy_amount() as f32,
+            Self::HarvestMineral => bodyspec.harvest_mineral_amount() as f32,
+            Self::HarvestDeposit => bodyspec.harvest_deposit_amount() as f32,
+            Self::Build => bodyspec.build_amount(),
+            Self::Repair => bodyspec.repair_amount(),
+            Self::Dismantle => bodyspec.dismantle_damage() as f32,
+            Self::UpgradeController => bodyspec.upgrade_controller_amount(),
+            Self::ReserveController => bodyspec.reserve_controller_amount() as f32,
+        }
+    }
+}
+
+/// Describes how much of a capability was lost when downgrading a body.
+///
+/// With the `serde` feature enabled, a CapabilityLoss is represented as a map with the keys
+/// `capability`, `ideal`, and `downgraded`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CapabilityLoss {
+    /// The capability that was reduced
+    pub capability: BodyCapability,
+
+    /// The capability of the ideal body
+    pub ideal: f32,
+
+    /// The capability of the downgraded body
+    pub downgraded: f32,
+}
+
+impl CapabilityLoss {
+    /// The fraction of the ideal capability that the downgraded body retains, between 0 and 1.
+    pub fn fraction_retained(&self) -> f32 {
+        if self.ideal > 0.0 {
+            self.downgraded / self.ideal
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The result of downgrading a body to fit an energy budget.
+///
+/// With the `serde` feature enabled, a BodyDowngrade is represented as a map with the keys
+/// `bodyspec` and `lost_capabilities`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BodyDowngrade {
+    /// The downgraded body
+    pub bodyspec: BodySpec,
+
+    /// Every capability that the downgraded body has less of than the ideal body, in the order of
+    /// [ALL_BODY_CAPABILITIES]
+    pub lost_capabilities: Vec<CapabilityLoss>,
+}
+
+/// Reduces a body until it fits within the energy budget and
+/// [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE), while preserving the ratios between its
+/// parts as closely as possible.
+///
+/// Parts that are identical, including their boosts, are counted together, and each of these
+/// counts is scaled down proportionally, keeping at least one of each. The largest such body that
+/// fits is chosen. The remaining parts keep their positions relative to each other from the ideal
+/// body, so interleaved layouts stay interleaved.
+///
+/// If the ideal body already fits, it's returned unchanged. Returns
+/// [InsufficientEnergy](BodyScalingError::InsufficientEnergy) if even a single part of each kind
+/// doesn't fit.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_scaling::{BodyCapability, downgrade_body};
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+///
+/// // A worker with a 2:1:1 ratio of Work:Carry:Move parts
+/// let ideal = generate_bodyspec_from_string("10W5C5M").unwrap();
+/// let downgrade = downgrade_body(&ideal, 600).unwrap();
+/// assert_eq!(generate_bodyspec_from_string("4W2C2M").unwrap(), downgrade.bodyspec);
+///
+/// let build_loss = downgrade.lost_capabilities.iter().find(|l| l.capability == BodyCapability::Build).unwrap();
+/// assert_eq!(0.4, build_loss.fraction_retained());
+/// ```
+pub fn downgrade_body(ideal: &BodySpec, energy_budget: u32) -> Result<BodyDowngrade, BodyScalingError> {
+    let parts = ideal.part_specs();
+
+    // Count each distinct part, in order of first appearance
+    let mut kinds: Vec<(PartSpec, usize)> = Vec::new();
+    for p in parts {
+        match kinds.iter_mut().find(|(kind, _)| kind == p) {
+            Some((_, count)) => *count += 1,
+            None => kinds.push((*p, 1)),
+        }
+    }
+
+    let max_parts = parts.len().min(MAX_CREEP_SIZE as usize);
+    let kind_counts = (kinds.len()..=max_parts).rev()
+        .filter_map(|total| apportion_parts(&kinds, parts.len(), total))
+        .find(|counts| kinds.iter().zip(counts).map(|((kind, _), n)| kind.part.cost() * *n as u32).sum::<u32>() <= energy_budget)
+        .ok_or(BodyScalingError::InsufficientEnergy)?;
+
+    // Keep the parts of each kind spread evenly across that kind's positions in the ideal body
+    let mut seen = vec![0; kinds.len()];
+    let body = parts.iter()
+        .filter(|p| {
+            // Unwrap is safe since every part was counted above
+            let k = kinds.iter().position(|(kind, _)| kind == *p).unwrap();
+            let (i, n, c) = (seen[k], kind_counts[k], kinds[k].1);
+            seen[k] += 1;
+            (i + 1) * n / c > i * n / c
+        })
+        .copied()
+        .collect();
+    let bodyspec = BodySpec::raw_new(body);
+
+    let lost_capabilities = ALL_BODY_CAPABILITIES.iter()
+        .map(|capability| CapabilityLoss {
+            capability: *capability,
+            ideal: capability.measure(ideal),
+            downgraded: capability.measure(&bodyspec),
+        })
+        .filter(|loss| loss.downgraded < loss.ideal)
+        .collect();
+
+    Ok(BodyDowngrade {
+        bodyspec,
+        lost_capabilities,
+    })
+}
+
+/// Splits a total number of parts between kinds of parts, proportionally to how many of each
+/// there are, using the largest remainder method. Every kind gets at least one part.
+///
+/// Returns None if the total is too small to give every kind a part.
+fn apportion_parts(kinds: &[(PartSpec, usize)], num_parts: usize, total: usize) -> Option<Vec<usize>> {
+    let quotas: Vec<(usize, usize)> = kinds.iter()
+        .map(|(_, count)| (count * total / num_parts, count * total % num_parts))
+        .collect();
+
+    let mut counts: Vec<usize> = quotas.iter().map(|(whole, _)| (*whole).max(1)).collect();
+    let assigned: usize = counts.iter().sum();
+    if assigned > total {
+        return None;
+    }
+
+    // Hand out the remaining parts to the kinds with the largest remainders, skipping any that
+    // were already rounded up to a single part
+    let mut candidates: Vec<usize> = (0..kinds.len())
+        .filter(|k| quotas[*k].0 > 0 && quotas[*k].1 > 0)
+        .collect();
+    candidates.sort_by_key(|k| std::cmp::Reverse(quotas[*k].1));
+
+    let remaining = total - assigned;
+    if remaining > candidates.len() {
+        return None;
+    }
+    candidates.into_iter().take(remaining).for_each(|k| counts[k] += 1);
+    Some(counts)
+}
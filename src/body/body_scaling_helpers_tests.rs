@@ -0,0 +1,191 @@
+use screeps::Part;
+use screeps::constants::MAX_CREEP_SIZE;
+
+use super::body_generation;
+use super::body_scaling::{ALL_BODY_CAPABILITIES, BodyCapability, BodyScalingError, BodyTemplate, downgrade_body};
+use crate::body::{BodySpec, PartSpec};
+use crate::boost::AbstractBoost;
+
+
+#[test]
+fn scale_repeats_pattern_within_energy_budget() {
+    let template = BodyTemplate::from_strings("", "WCM", "").unwrap();
+    let test_data = [
+        (200, "WCM"),
+        (300, "WCM"),
+        (399, "WCM"),
+        (400, "2WCM"),
+        (550, "2WCM"),
+        (800, "4WCM"),
+        (1300, "6WCM"),
+        (12900, "16WCM"),
+    ];
+
+    for (energy_budget, expected) in test_data {
+        let result = template.scale(energy_budget).unwrap();
+        assert_eq!(body_generation::generate_bodyspec_from_string(expected).unwrap(), result, "Energy budget: {}", energy_budget);
+        assert!(result.energy_to_spawn() <= energy_budget, "Energy budget: {}", energy_budget);
+    }
+}
+
+#[test]
+fn scale_includes_prefix_and_suffix() {
+    let template = BodyTemplate::from_strings("2T", "RM", "HM").unwrap();
+    assert_eq!(body_generation::generate_bodyspec_from_string("2T RM HM").unwrap(), template.scale(580).unwrap());
+    assert_eq!(body_generation::generate_bodyspec_from_string("2T 2RM HM").unwrap(), template.scale(780).unwrap());
+
+    // The prefix and suffix count towards the part limit
+    let result = template.scale(100000).unwrap();
+    assert_eq!(body_generation::generate_bodyspec_from_string("2T 23RM HM").unwrap(), result);
+    assert_eq!(MAX_CREEP_SIZE as usize, result.part_specs().len());
+}
+
+#[test]
+fn scale_keeps_boosts() {
+    let t3_move = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T3Move);
+    let t3_work = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    let template = BodyTemplate::new(&[], &[t3_work, t3_work, t3_work, t3_move], &[]);
+
+    let result = template.scale(2000).unwrap();
+    assert_eq!(20, result.part_specs().len());
+    assert_eq!(body_generation::generate_bodyspec_from_string("5(3W[T3Dismantle]M[T3Move])").unwrap(), result);
+}
+
+#[test]
+fn scale_returns_errors_for_unscalable_templates() {
+    let template = BodyTemplate::from_strings("T", "", "M").unwrap();
+    assert_eq!(Err(BodyScalingError::EmptyPattern), template.scale(10000));
+
+    let template = BodyTemplate::from_strings("40T", "6HM", "").unwrap();
+    assert_eq!(Err(BodyScalingError::TooManyParts), template.scale(100000));
+
+    let template = BodyTemplate::from_strings("L", "M", "").unwrap();
+    assert_eq!(Err(BodyScalingError::InsufficientEnergy), template.scale(649));
+    assert_eq!(Ok(body_generation::generate_bodyspec_from_string("LM").unwrap()), template.scale(650));
+}
+
+#[test]
+fn with_repeats_ignores_energy_but_checks_size() {
+    let template = BodyTemplate::from_strings("", "CM", "").unwrap();
+    assert_eq!(Ok(body_generation::generate_bodyspec_from_string("CM").unwrap()), template.with_repeats(1));
+    assert_eq!(Ok(body_generation::generate_bodyspec_from_string("25CM").unwrap()), template.with_repeats(25));
+    assert_eq!(Err(BodyScalingError::TooManyParts), template.with_repeats(26));
+    assert_eq!(Err(BodyScalingError::TooManyParts), template.with_repeats(usize::MAX));
+
+    let template = BodyTemplate::from_strings("W", "CM", "").unwrap();
+    assert_eq!(Ok(body_generation::generate_bodyspec_from_string("W").unwrap()), template.with_repeats(0));
+}
+
+#[test]
+fn from_strings_returns_parse_errors() {
+    let result = BodyTemplate::from_strings("", "60W", "");
+    assert_eq!(Err(body_generation::GenerateBodyError::TooManyBodyParts), result);
+
+    let result = BodyTemplate::from_strings("2(T", "W", "");
+    assert_eq!(Err(body_generation::GenerateBodyError::InvalidString), result);
+}
+
+#[test]
+fn downgrade_body_returns_ideal_body_when_it_fits() {
+    let ideal = body_generation::generate_bodyspec_from_string("10W5C5M").unwrap();
+    let downgrade = downgrade_body(&ideal, ideal.energy_to_spawn()).unwrap();
+    assert_eq!(ideal, downgrade.bodyspec);
+    assert!(downgrade.lost_capabilities.is_empty());
+
+    let downgrade = downgrade_body(&BodySpec::new(&[]), 0).unwrap();
+    assert!(downgrade.bodyspec.part_specs().is_empty());
+}
+
+#[test]
+fn downgrade_body_preserves_ratios() {
+    let ideal = body_generation::generate_bodyspec_from_string("10W5C5M").unwrap();
+    let test_data = [
+        (1499, "9W5C5M"),
+        (1000, "7W3C3M"),
+        (800, "5W3C3M"),
+        (600, "4W2C2M"),
+        (300, "2W C M"),
+        (200, "WCM"),
+    ];
+
+    for (energy_budget, expected) in test_data {
+        let downgrade = downgrade_body(&ideal, energy_budget).unwrap();
+        assert_eq!(body_generation::generate_bodyspec_from_string(expected).unwrap(), downgrade.bodyspec, "Energy budget: {}", energy_budget);
+        assert!(downgrade.bodyspec.energy_to_spawn() <= energy_budget, "Energy budget: {}", energy_budget);
+    }
+
+    assert_eq!(Err(BodyScalingError::InsufficientEnergy), downgrade_body(&ideal, 199));
+}
+
+#[test]
+fn downgrade_body_keeps_layout_of_ideal_body() {
+    // Interleaved parts stay interleaved
+    let ideal = body_generation::generate_bodyspec_from_string("10CM").unwrap();
+    let downgrade = downgrade_body(&ideal, 400).unwrap();
+    assert_eq!(body_generation::generate_bodyspec_from_string("4CM").unwrap(), downgrade.bodyspec);
+
+    // Tough parts stay at the front, and Heal parts at the back
+    let ideal = body_generation::generate_bodyspec_from_string("4T 10R 16M 4H").unwrap();
+    let downgrade = downgrade_body(&ideal, 2000).unwrap();
+    let parts = downgrade.bodyspec.get_parts();
+    assert_eq!(Part::Tough, parts[0]);
+    assert_eq!(Part::Heal, parts[parts.len() - 1]);
+    assert!(downgrade.bodyspec.energy_to_spawn() <= 2000);
+}
+
+#[test]
+fn downgrade_body_keeps_boosts() {
+    let ideal = body_generation::generate_bodyspec_from_string("10W[T3Harvest] 10W 5M[T3Move]").unwrap();
+    let downgrade = downgrade_body(&ideal, 1050).unwrap();
+    assert_eq!(body_generation::generate_bodyspec_from_string("5W[T3Harvest] 4W 2M[T3Move]").unwrap(), downgrade.bodyspec);
+}
+
+#[test]
+fn downgrade_body_fits_max_creep_size() {
+    let mut parts = vec![PartSpec::new_unboosted_part(Part::Carry); 40];
+    parts.extend(vec![PartSpec::new_unboosted_part(Part::Move); 20]);
+    let ideal = BodySpec::new(&parts);
+    let downgrade = downgrade_body(&ideal, 100000).unwrap();
+    assert_eq!(MAX_CREEP_SIZE as usize, downgrade.bodyspec.part_specs().len());
+    assert_eq!(body_generation::generate_bodyspec_from_string("33C 17M").unwrap(), downgrade.bodyspec);
+}
+
+#[test]
+fn downgrade_body_reports_lost_capabilities() {
+    let ideal = body_generation::generate_bodyspec_from_string("10W5C5M").unwrap();
+    let downgrade = downgrade_body(&ideal, 600).unwrap();
+
+    let lost: Vec<BodyCapability> = downgrade.lost_capabilities.iter().map(|l| l.capability).collect();
+    let expected = vec![
+        BodyCapability::EffectiveHits,
+        BodyCapability::CarryCapacity,
+        BodyCapability::FatigueReduction,
+        BodyCapability::HarvestEnergy,
+        BodyCapability::HarvestMineral,
+        BodyCapability::HarvestDeposit,
+        BodyCapability::Build,
+        BodyCapability::Repair,
+        BodyCapability::Dismantle,
+        BodyCapability::UpgradeController,
+    ];
+    assert_eq!(expected, lost);
+
+    for loss in &downgrade.lost_capabilities {
+        assert_eq!(loss.ideal, loss.capability.measure(&ideal));
+        assert_eq!(loss.downgraded, loss.capability.measure(&downgrade.bodyspec));
+    }
+
+    let carry_loss = downgrade.lost_capabilities[1];
+    assert_eq!(250.0, carry_loss.ideal);
+    assert_eq!(100.0, carry_loss.downgraded);
+    assert_eq!(0.4, carry_loss.fraction_retained());
+}
+
+#[test]
+fn body_capability_measures_every_capability() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("T W C A R H L M").unwrap();
+    for capability in ALL_BODY_CAPABILITIES {
+        assert!(capability.measure(&bodyspec) > 0.0, "Capability: {:?}", capability);
+        assert_eq!(0.0, capability.measure(&BodySpec::new(&[])), "Capability: {:?}", capability);
+    }
+}
@@ -5,12 +5,21 @@ use screeps::constants::{
     ATTACK_POWER,
     BUILD_POWER,
     CARRY_CAPACITY,
+    CONTROLLER_CLAIM_DOWNGRADE,
+    CONTROLLER_RESERVE,
+    CREEP_CLAIM_LIFE_TIME,
+    CREEP_LIFE_TIME,
     CREEP_SPAWN_TIME,
     DISMANTLE_POWER,
     HARVEST_DEPOSIT_POWER,
     HARVEST_MINERAL_POWER,
     HARVEST_POWER,
     HEAL_POWER,
+    LAB_BOOST_ENERGY,
+    LAB_BOOST_MINERAL,
+    LAB_REACTION_AMOUNT,
+    LAB_UNBOOST_ENERGY,
+    LAB_UNBOOST_MINERAL,
     MAX_CREEP_SIZE,
     RANGED_ATTACK_POWER,
     RANGED_HEAL_POWER,
@@ -30,6 +39,10 @@ use screeps::constants::extra::{
 use screeps::constants::Boost;
 use screeps::objects::output::BodyPart;
 use crate::boost::boost::AbstractBoost;
+use crate::boost::bom::ReactionBillOfMaterials;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 const fn ranged_mass_attack_power_at_distance(distance: u8) -> u32 {
     match distance {
@@ -55,7 +68,12 @@ const fn ranged_mass_attack_power_at_distance(distance: u8) -> u32 {
 /// assert_eq!(HARVEST_POWER, unboosted_part.get_harvest_energy_amount());
 /// assert_eq!(HARVEST_POWER * 5, t2_part.get_harvest_energy_amount());
 /// ```
+///
+/// With the `serde` feature enabled, a PartSpec is represented as a map with the keys `part`
+/// (the part name used by the game, i.e. `"work"`), `hits`, and `boost` (an [AbstractBoost]
+/// variant name, or null).
 #[derive(Debug, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PartSpec {
   pub part: Part,
   pub hits: u32,
@@ -122,6 +140,10 @@ impl PartSpec {
     /// For Carry parts, this assumes that the part is not empty, as long as its hits > 0. Carry
     /// parts with hits = 0 do not contribute to fatigue since they are not carrying anything. This
     /// has been verified in the engine code.
+    ///
+    /// To account for partially filled stores, use
+    /// [move_net_exhaustion_with_store](BodySpec::move_net_exhaustion_with_store) or
+    /// [simulate_movement_with_store](BodySpec::simulate_movement_with_store) instead.
     pub const fn get_fatigue_generation(&self, tile_cost: u32) -> u32 {
         match self.part {
             // The docs phrase things so that Move parts should be contributing to fatigue as well
@@ -411,6 +433,43 @@ impl PartSpec {
         }
     }
 
+    /// Calculates how many ticks of reservation this part adds when using
+    /// [reserve_controller](screeps::Creep::reserve_controller).
+    ///
+    /// Note: This does not take into account whether the part has enough hits to be
+    /// active. Filtering for inactive parts must be done by the caller.
+    pub const fn get_reserve_controller_amount(&self) -> u32 {
+        match self.part {
+            // Claim parts can't be boosted
+            Part::Claim => CONTROLLER_RESERVE,
+            _ => 0,
+        }
+    }
+
+    /// Calculates how many ticks this part removes from another player's reservation when using
+    /// [attack_controller](screeps::Creep::attack_controller) on a reserved controller.
+    ///
+    /// Note: This does not take into account whether the part has enough hits to be
+    /// active. Filtering for inactive parts must be done by the caller.
+    pub const fn get_attack_controller_reservation_reduction(&self) -> u32 {
+        match self.part {
+            Part::Claim => CONTROLLER_RESERVE,
+            _ => 0,
+        }
+    }
+
+    /// Calculates how many ticks this part removes from the downgrade timer when using
+    /// [attack_controller](screeps::Creep::attack_controller) on an owned controller.
+    ///
+    /// Note: This does not take into account whether the part has enough hits to be
+    /// active. Filtering for inactive parts must be done by the caller.
+    pub const fn get_attack_controller_downgrade_reduction(&self) -> u32 {
+        match self.part {
+            Part::Claim => CONTROLLER_CLAIM_DOWNGRADE,
+            _ => 0,
+        }
+    }
+
     /// Calculates how much damage this part can currently take; i.e. its effective hits.
     ///
     /// This takes into account Tough boosts, if this is a Tough part. Otherwise, this is equal to
@@ -446,7 +505,28 @@ impl PartSpec {
             },
             _ => self.hits as f32,
         }
-    } 
+    }
+
+    /// Calculates the multiplier applied to any damage this part absorbs.
+    ///
+    /// This is the Tough boost multiplier for boosted Tough parts, and 1.0 for every other part.
+    ///
+    /// Note: This matches the `damageRatio` variable from the damage calculations in the [engine
+    /// code](https://github.com/screeps/engine/blob/master/src/processor/intents/creeps/tick.js#L20).
+    pub const fn get_damage_multiplier(&self) -> f32 {
+        match self.part {
+            Part::Tough => {
+                match self.boost {
+                    None => 1.0,
+                    Some(abstract_boost) => match AbstractBoost::const_to_boost(&abstract_boost) {
+                        Boost::Tough(val) => val,
+                        _ => 1.0,
+                    },
+                }
+            },
+            _ => 1.0,
+        }
+    }
 
 }
 
@@ -560,7 +640,11 @@ pub enum BodySpecValidationError {
 /// assert_eq!(40, unboosted_bodyspec.swamp_move_net_exhaustion());
 /// assert_eq!(38, t2_bodyspec.swamp_move_net_exhaustion());
 /// ```
+///
+/// With the `serde` feature enabled, a BodySpec is represented as a sequence of [PartSpec]s,
+/// ordered front-to-back. Deserialized bodies are not validated.
 #[derive(Debug, PartialEq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct BodySpec {
   body: Vec<PartSpec>,
 }
@@ -651,6 +735,24 @@ impl BodySpec {
         v
     }
 
+    /// Returns the part specifications that make up this creep body.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// 
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let w = PartSpec::new(Part::Work, 50, None);
+    /// let body = vec!(w, m);
+    /// let bodyspec = BodySpec::new(&body);
+    /// let part_specs = bodyspec.part_specs();
+    /// assert_eq!(w, part_specs[0]);
+    /// assert_eq!(m, part_specs[1]);
+    /// ```
+    pub fn part_specs(&self) -> &[PartSpec] {
+        &self.body
+    }
+
     /// Calculates the current hits that a creep has.
     ///
     /// ```rust
@@ -694,6 +796,204 @@ impl BodySpec {
         self.body.iter().fold(0.0, |acc, p| acc + p.get_damage_capacity()).floor() as u32
     }
 
+    /// Calculates the maximum hits that a creep with this body can have.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::extra::CREEP_HITS_PER_PART;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// // Create a body with a damaged part
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let w = PartSpec::new(Part::Work, 50, None);
+    /// let body = vec!(w, m);
+    /// let bodyspec = BodySpec::new(&body);
+    /// assert_eq!(CREEP_HITS_PER_PART * 2, bodyspec.hits_max());
+    /// ```
+    pub fn hits_max(&self) -> u32 {
+        self.body.len() as u32 * CREEP_HITS_PER_PART
+    }
+
+    /// Calculates how many hits a creep would actually lose from the specified amount of incoming
+    /// damage, after any boosted Tough parts have reduced it.
+    ///
+    /// Parts absorb the damage front-to-back, with each part absorbing up to its
+    /// [damage capacity](PartSpec::get_damage_capacity). Fractional damage that overflows from one
+    /// part carries into the next, and the total damage reduction is only rounded once all the
+    /// parts have been processed, just like the [engine
+    /// code](https://github.com/screeps/engine/blob/master/src/processor/intents/creeps/tick.js#L20).
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    ///
+    /// // Unboosted bodies take the full amount of damage
+    /// let bodyspec = BodySpec::new(&[m, m]);
+    /// assert_eq!(100, bodyspec.damage_taken(100));
+    ///
+    /// // T3 Tough parts reduce the damage they absorb by 70%
+    /// let bodyspec = BodySpec::new(&[t, m]);
+    /// assert_eq!(30, bodyspec.damage_taken(100));
+    /// ```
+    pub fn damage_taken(&self, amount: u32) -> u32 {
+        let mut damage_reduction: f32 = 0.0;
+        let mut damage_remaining = amount as f32;
+
+        for p in self.body.iter() {
+            if damage_remaining <= 0.0 {
+                break;
+            }
+
+            let damage_absorbed = p.get_damage_capacity().min(damage_remaining);
+            damage_reduction += damage_absorbed * (1.0 - p.get_damage_multiplier());
+            damage_remaining -= damage_absorbed;
+        }
+
+        amount.saturating_sub(damage_reduction.round() as u32)
+    }
+
+    /// Simulates the specified amount of incoming damage being applied to this body, returning the
+    /// resulting body.
+    ///
+    /// Damage is reduced by boosted Tough parts as described in
+    /// [damage_taken](BodySpec::damage_taken), and the remaining hits are then spread back across
+    /// the parts the same way the engine does: parts at the back of the body are filled up first,
+    /// so damage destroys parts front-to-back.
+    ///
+    /// If the damage is enough to kill the creep, every part in the returned body will have 0 hits.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let h = PartSpec::new_unboosted_part(Part::Heal);
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    /// let bodyspec = BodySpec::new(&[t, h, m]);
+    ///
+    /// // The Tough part soaks up 70% of the damage it absorbs
+    /// let damaged = bodyspec.apply_damage(100);
+    /// assert_eq!(270, damaged.hits());
+    /// assert_eq!(70, damaged.part_specs()[0].hits);
+    ///
+    /// // Once the Tough part is gone, the Heal part is next in line
+    /// let damaged = bodyspec.apply_damage(350);
+    /// assert_eq!(0, damaged.part_specs()[0].hits);
+    /// assert_eq!(83, damaged.part_specs()[1].hits);
+    /// assert_eq!(100, damaged.part_specs()[2].hits);
+    ///
+    /// // Enough damage destroys the Heal part entirely
+    /// let damaged = bodyspec.apply_damage(450);
+    /// assert_eq!(0, damaged.part_specs()[1].hits);
+    /// assert_eq!(83, damaged.part_specs()[2].hits);
+    /// assert_eq!(0, damaged.heal_amount());
+    /// ```
+    pub fn apply_damage(&self, amount: u32) -> BodySpec {
+        let remaining_hits = self.hits().saturating_sub(self.damage_taken(amount));
+        self.with_total_hits(remaining_hits)
+    }
+
+    /// Simulates the specified amount of healing being applied to this body, returning the
+    /// resulting body.
+    ///
+    /// The healed hits are added to the creep's total hits, capped at
+    /// [hits_max](BodySpec::hits_max), and then spread back across the parts the same way the
+    /// engine does: parts at the back of the body are restored first.
+    ///
+    /// A body with 0 hits is a dead creep, and can't be healed; it is returned unchanged.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let a = PartSpec::new_unboosted_part(Part::Attack);
+    /// let h = PartSpec::new_unboosted_part(Part::Heal);
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let bodyspec = BodySpec::new(&[a, h, m]);
+    ///
+    /// // Destroy the Attack part and most of the Heal part
+    /// let damaged = bodyspec.apply_damage(190);
+    /// assert_eq!(0, damaged.attack_damage());
+    /// assert_eq!(10, damaged.part_specs()[1].hits);
+    ///
+    /// // Healing restores the Heal part before the Attack part
+    /// let healed = damaged.apply_heal(100);
+    /// assert_eq!(10, healed.part_specs()[0].hits);
+    /// assert_eq!(100, healed.part_specs()[1].hits);
+    /// assert_ne!(0, healed.attack_damage());
+    ///
+    /// // Healing never goes past the maximum hits of the body
+    /// let healed = damaged.apply_heal(1000);
+    /// assert_eq!(bodyspec, healed);
+    /// ```
+    pub fn apply_heal(&self, amount: u32) -> BodySpec {
+        let current_hits = self.hits();
+        if current_hits == 0 {
+            return self.clone();
+        }
+
+        let healed_hits = current_hits.saturating_add(amount).min(self.hits_max());
+        self.with_total_hits(healed_hits)
+    }
+
+    /// Simulates a single tick in which this body receives both damage and healing, returning the
+    /// resulting body.
+    ///
+    /// This follows the engine's order of operations: the damage is reduced by boosted Tough parts
+    /// based on the body's state at the start of the tick, then both the damage and the healing
+    /// are applied to the creep's total hits at once. The creep only dies if the healing isn't
+    /// enough to offset the damage, so healing can save a creep that would otherwise have been
+    /// killed by the damage alone.
+    ///
+    /// If the creep dies, every part in the returned body will have 0 hits.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let h = PartSpec::new_unboosted_part(Part::Heal);
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let bodyspec = BodySpec::new(&[h, m]);
+    ///
+    /// // On its own, this damage would kill the creep
+    /// assert_eq!(0, bodyspec.apply_damage(250).hits());
+    ///
+    /// // But the heal arrives in the same tick, so the creep survives
+    /// let result = bodyspec.apply_damage_and_heal(250, 100);
+    /// assert_eq!(50, result.hits());
+    /// ```
+    pub fn apply_damage_and_heal(&self, damage: u32, heal: u32) -> BodySpec {
+        let current_hits = self.hits();
+        if current_hits == 0 {
+            return self.clone();
+        }
+
+        let damage_taken = self.damage_taken(damage);
+        let resulting_hits = current_hits.saturating_add(heal).saturating_sub(damage_taken).min(self.hits_max());
+        self.with_total_hits(resulting_hits)
+    }
+
+    /// Creates a copy of this body with the provided total hits spread across the parts.
+    ///
+    /// Hits are assigned back-to-front, with each part capped at
+    /// [CREEP_HITS_PER_PART](screeps::constants::extra::CREEP_HITS_PER_PART). This matches the
+    /// `recalcBody` function in the [engine
+    /// code](https://github.com/screeps/engine/blob/master/src/processor/intents/creeps/tick.js).
+    fn with_total_hits(&self, total_hits: u32) -> BodySpec {
+        let mut hits_remaining = total_hits;
+        let mut body = self.body.clone();
+        for p in body.iter_mut().rev() {
+            p.hits = hits_remaining.min(CREEP_HITS_PER_PART);
+            hits_remaining -= p.hits;
+        }
+        BodySpec::raw_new(body)
+    }
+
     fn get_u32_active_parts_of_type(&self, part: Part) -> std::iter::Filter<std::slice::Iter<'_, PartSpec>, fn(&&PartSpec) -> bool> {
         let f = match part {
             Part::Work => filter_is_work_part,
@@ -726,6 +1026,43 @@ impl BodySpec {
         self.get_u32_active_parts_of_type(Part::Carry).fold(0, |acc, p| acc + p.get_carry_capacity())
     }
 
+    /// Calculates how many Carry parts are considered non-empty when the creep is storing the
+    /// specified amount of resources.
+    ///
+    /// The engine fills active Carry parts starting from the back of the body, with each part
+    /// holding up to its [carry capacity](PartSpec::get_carry_capacity). Only these non-empty
+    /// Carry parts generate fatigue when moving, as per the `calcResourcesWeight` function in the
+    /// [engine code](https://github.com/screeps/engine/blob/master/src/utils.js).
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::CARRY_CAPACITY;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let c = PartSpec::new_unboosted_part(Part::Carry);
+    /// let bodyspec = BodySpec::new(&[c, c, c, m]);
+    ///
+    /// assert_eq!(0, bodyspec.loaded_carry_parts(0));
+    /// assert_eq!(1, bodyspec.loaded_carry_parts(1));
+    /// assert_eq!(1, bodyspec.loaded_carry_parts(CARRY_CAPACITY));
+    /// assert_eq!(2, bodyspec.loaded_carry_parts(CARRY_CAPACITY + 1));
+    /// assert_eq!(3, bodyspec.loaded_carry_parts(CARRY_CAPACITY * 3));
+    /// ```
+    pub fn loaded_carry_parts(&self, store_amount: u32) -> u32 {
+        let mut store_remaining = store_amount;
+        let mut loaded_parts = 0;
+        for p in self.get_u32_active_parts_of_type(Part::Carry).rev() {
+            if store_remaining == 0 {
+                break;
+            }
+
+            store_remaining = store_remaining.saturating_sub(p.get_carry_capacity());
+            loaded_parts += 1;
+        }
+        loaded_parts
+    }
+
     /// Calculates the melee attack damage that a creep can deal.
     ///
     /// ```rust
@@ -931,6 +1268,83 @@ impl BodySpec {
         self.get_u32_active_parts_of_type(Part::Work).fold(0, |acc, p| acc + p.get_harvest_deposit_amount())
     }
 
+    /// Calculates how many ticks of reservation a creep adds per tick when using
+    /// [reserve_controller](screeps::Creep::reserve_controller).
+    ///
+    /// Note that a reservation also counts down by 1 every tick, so the net gain per tick is 1
+    /// less than this amount.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::CONTROLLER_RESERVE;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let l = PartSpec::new_unboosted_part(Part::Claim);
+    /// let body = vec!(l, l, m, m);
+    /// let bodyspec = BodySpec::new(&body);
+    /// assert_eq!(CONTROLLER_RESERVE * 2, bodyspec.reserve_controller_amount());
+    /// ```
+    pub fn reserve_controller_amount(&self) -> u32 {
+        self.get_u32_active_parts_of_type(Part::Claim).fold(0, |acc, p| acc + p.get_reserve_controller_amount())
+    }
+
+    /// Calculates how many ticks a creep removes from another player's reservation when using
+    /// [attack_controller](screeps::Creep::attack_controller) on a reserved controller.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::CONTROLLER_RESERVE;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let l = PartSpec::new_unboosted_part(Part::Claim);
+    /// let body = vec!(l, l, m, m);
+    /// let bodyspec = BodySpec::new(&body);
+    /// assert_eq!(CONTROLLER_RESERVE * 2, bodyspec.attack_controller_reservation_reduction());
+    /// ```
+    pub fn attack_controller_reservation_reduction(&self) -> u32 {
+        self.get_u32_active_parts_of_type(Part::Claim).fold(0, |acc, p| acc + p.get_attack_controller_reservation_reduction())
+    }
+
+    /// Calculates how many ticks a creep removes from the downgrade timer when using
+    /// [attack_controller](screeps::Creep::attack_controller) on an owned controller.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::CONTROLLER_CLAIM_DOWNGRADE;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let l = PartSpec::new_unboosted_part(Part::Claim);
+    /// let body = vec!(l, l, m, m);
+    /// let bodyspec = BodySpec::new(&body);
+    /// assert_eq!(CONTROLLER_CLAIM_DOWNGRADE * 2, bodyspec.attack_controller_downgrade_reduction());
+    /// ```
+    pub fn attack_controller_downgrade_reduction(&self) -> u32 {
+        self.get_u32_active_parts_of_type(Part::Claim).fold(0, |acc, p| acc + p.get_attack_controller_downgrade_reduction())
+    }
+
+    /// Whether a creep with this body can use [claim_controller](screeps::Creep::claim_controller),
+    /// which requires at least one active Claim part.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let l = PartSpec::new_unboosted_part(Part::Claim);
+    /// assert!(BodySpec::new(&[l, m]).can_claim());
+    /// assert!(!BodySpec::new(&[m]).can_claim());
+    ///
+    /// // A destroyed Claim part doesn't count
+    /// let dead_l = PartSpec::new(Part::Claim, 0, None);
+    /// assert!(!BodySpec::new(&[dead_l, m]).can_claim());
+    /// ```
+    pub fn can_claim(&self) -> bool {
+        self.get_u32_active_parts_of_type(Part::Claim).next().is_some()
+    }
+
     /// Calculates the amount of energy needed to spawn a creep with this body.
     ///
     /// ```rust
@@ -964,6 +1378,218 @@ impl BodySpec {
         self.body.iter().fold(0, |acc, _| acc + CREEP_SPAWN_TIME)
     }
 
+    /// Calculates the compounds needed to boost a creep with this body.
+    ///
+    /// Each boosted part needs [LAB_BOOST_MINERAL] of the compound associated with its boost. The
+    /// compounds are grouped by resource, so they can be fed straight into the reaction planning
+    /// helpers on [ReactionBillOfMaterials].
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{LAB_BOOST_MINERAL, ResourceType};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let t3_m = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T3Move);
+    /// let t1_m = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T1Move);
+    /// let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    /// let bodyspec = BodySpec::new(&[t3_w, t3_w, t1_m, t3_m]);
+    ///
+    /// let bom = bodyspec.boost_compounds_needed();
+    /// assert_eq!(LAB_BOOST_MINERAL * 2, bom.resource_amount(&ResourceType::CatalyzedZynthiumAcid));
+    /// assert_eq!(LAB_BOOST_MINERAL, bom.resource_amount(&ResourceType::CatalyzedZynthiumAlkalide));
+    /// assert_eq!(LAB_BOOST_MINERAL, bom.resource_amount(&ResourceType::ZynthiumOxide));
+    /// ```
+    pub fn boost_compounds_needed(&self) -> ReactionBillOfMaterials {
+        let mut bom = ReactionBillOfMaterials::new();
+        for boost in self.body.iter().filter_map(|p| p.boost) {
+            bom.add_resource(&boost.associated_resource(), LAB_BOOST_MINERAL);
+        }
+        bom
+    }
+
+    /// Calculates the lab energy needed to boost a creep with this body.
+    ///
+    /// Each boosted part needs [LAB_BOOST_ENERGY], regardless of the boost used.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::LAB_BOOST_ENERGY;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    /// let bodyspec = BodySpec::new(&[t3_w, t3_w, m]);
+    /// assert_eq!(LAB_BOOST_ENERGY * 2, bodyspec.boost_energy_needed());
+    /// ```
+    pub fn boost_energy_needed(&self) -> u32 {
+        self.body.iter().filter(|p| p.boost.is_some()).count() as u32 * LAB_BOOST_ENERGY
+    }
+
+    /// Calculates the compounds returned by [unboost_creep](screeps::StructureLab::unboost_creep)
+    /// for a creep with this body.
+    ///
+    /// Each boosted part returns [LAB_UNBOOST_MINERAL] of the compound associated with its boost,
+    /// as per the [engine
+    /// code](https://github.com/screeps/engine/blob/master/src/processor/intents/labs/unboost-creep.js).
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{LAB_UNBOOST_MINERAL, ResourceType};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    /// let bodyspec = BodySpec::new(&[t3_w, t3_w, m]);
+    ///
+    /// let bom = bodyspec.unboost_compounds_returned();
+    /// assert_eq!(LAB_UNBOOST_MINERAL * 2, bom.resource_amount(&ResourceType::CatalyzedZynthiumAcid));
+    /// ```
+    pub fn unboost_compounds_returned(&self) -> ReactionBillOfMaterials {
+        let mut bom = ReactionBillOfMaterials::new();
+        for boost in self.body.iter().filter_map(|p| p.boost) {
+            bom.add_resource(&boost.associated_resource(), LAB_UNBOOST_MINERAL);
+        }
+        bom
+    }
+
+    /// Calculates the energy returned by [unboost_creep](screeps::StructureLab::unboost_creep) for
+    /// a creep with this body.
+    ///
+    /// Each boosted part returns [LAB_UNBOOST_ENERGY], which is currently 0.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::LAB_UNBOOST_ENERGY;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    /// let bodyspec = BodySpec::new(&[t3_w, t3_w, m]);
+    /// assert_eq!(LAB_UNBOOST_ENERGY * 2, bodyspec.unboost_energy_returned());
+    /// ```
+    pub fn unboost_energy_returned(&self) -> u32 {
+        self.body.iter().filter(|p| p.boost.is_some()).count() as u32 * LAB_UNBOOST_ENERGY
+    }
+
+    /// Calculates the cooldown the lab will have after using
+    /// [unboost_creep](screeps::StructureLab::unboost_creep) on a creep with this body.
+    ///
+    /// The cooldown is the time the lab would need to produce the returned compounds from scratch:
+    /// each boosted part adds the [reaction time of the whole chain](AbstractBoost::reaction_chain_time)
+    /// that produces its boost for every [LAB_REACTION_AMOUNT] of the [LAB_UNBOOST_MINERAL]
+    /// returned, matching the engine.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{LAB_REACTION_AMOUNT, LAB_UNBOOST_MINERAL};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    /// use screeps_body_utils::boost::AbstractBoost;
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let t3_w = PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Dismantle);
+    /// let bodyspec = BodySpec::new(&[t3_w, t3_w, m]);
+    ///
+    /// let reactions_per_part = LAB_UNBOOST_MINERAL / LAB_REACTION_AMOUNT;
+    /// let expected_cooldown = 2 * reactions_per_part * AbstractBoost::T3Dismantle.reaction_chain_time();
+    /// assert_eq!(expected_cooldown, bodyspec.unboost_lab_cooldown());
+    /// ```
+    pub fn unboost_lab_cooldown(&self) -> u32 {
+        self.body.iter()
+            .filter_map(|p| p.boost)
+            .fold(0, |acc, boost| acc + boost.reaction_chain_time() * LAB_UNBOOST_MINERAL / LAB_REACTION_AMOUNT)
+    }
+
+    /// Returns the number of ticks a creep with this body will live for once spawned.
+    ///
+    /// Creeps with any Claim parts live for [CREEP_CLAIM_LIFE_TIME] ticks, while all other creeps
+    /// live for [CREEP_LIFE_TIME] ticks. Since the lifetime is fixed when the creep is spawned,
+    /// this counts Claim parts regardless of their current hits.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{CREEP_CLAIM_LIFE_TIME, CREEP_LIFE_TIME};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let l = PartSpec::new_unboosted_part(Part::Claim);
+    /// assert_eq!(CREEP_LIFE_TIME, BodySpec::new(&[m]).lifetime());
+    /// assert_eq!(CREEP_CLAIM_LIFE_TIME, BodySpec::new(&[l, m]).lifetime());
+    /// ```
+    pub fn lifetime(&self) -> u32 {
+        if self.body.iter().any(|p| p.part == Part::Claim) {
+            CREEP_CLAIM_LIFE_TIME
+        } else {
+            CREEP_LIFE_TIME
+        }
+    }
+
+    /// Calculates the total amount of energy a creep can harvest from a [Source](screeps::Source)
+    /// over its [lifetime](BodySpec::lifetime).
+    ///
+    /// This is an upper bound which assumes the creep harvests every tick of its life; it doesn't
+    /// account for travel time or the source running dry.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{CREEP_LIFE_TIME, HARVEST_POWER};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let w = PartSpec::new_unboosted_part(Part::Work);
+    /// let bodyspec = BodySpec::new(&[w, m]);
+    /// assert_eq!(HARVEST_POWER * CREEP_LIFE_TIME, bodyspec.lifetime_harvest_energy_amount());
+    /// ```
+    pub fn lifetime_harvest_energy_amount(&self) -> u32 {
+        self.harvest_energy_amount() * self.lifetime()
+    }
+
+    /// Calculates the total amount of progress a creep can add to construction sites over its
+    /// [lifetime](BodySpec::lifetime).
+    ///
+    /// This is an upper bound which assumes the creep builds every tick of its life; it doesn't
+    /// account for travel time or refilling its energy.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::{BUILD_POWER, CREEP_LIFE_TIME};
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let w = PartSpec::new_unboosted_part(Part::Work);
+    /// let bodyspec = BodySpec::new(&[w, m]);
+    /// assert_eq!((BUILD_POWER * CREEP_LIFE_TIME) as f32, bodyspec.lifetime_build_amount());
+    /// ```
+    pub fn lifetime_build_amount(&self) -> f32 {
+        self.build_amount() * self.lifetime() as f32
+    }
+
+    /// Calculates the total amount of resources a creep can move over its
+    /// [lifetime](BodySpec::lifetime), given the number of ticks a full round trip takes.
+    ///
+    /// Only complete round trips are counted, and each round trip moves a full load equal to the
+    /// creep's [carry capacity](BodySpec::carry_capacity). Returns 0 if `round_trip_ticks` is 0.
+    ///
+    /// ```rust
+    /// use screeps::Part;
+    /// use screeps::constants::CARRY_CAPACITY;
+    /// use screeps_body_utils::body::{BodySpec, PartSpec};
+    ///
+    /// let m = PartSpec::new_unboosted_part(Part::Move);
+    /// let c = PartSpec::new_unboosted_part(Part::Carry);
+    /// let bodyspec = BodySpec::new(&[c, c, m]);
+    ///
+    /// // 1500 ticks of life fits 13 complete round trips of 110 ticks each
+    /// assert_eq!(13 * 2 * CARRY_CAPACITY, bodyspec.lifetime_carry_throughput(110));
+    /// ```
+    pub fn lifetime_carry_throughput(&self, round_trip_ticks: u32) -> u32 {
+        let round_trips = self.lifetime().checked_div(round_trip_ticks).unwrap_or(0);
+        self.carry_capacity() * round_trips
+    }
+
     /// Calculates the net exhaustion that results from moving this body onto a tile with a
     /// specific cost.
     fn tile_move_net_exhaustion(&self, tile_cost: u32) -> u32 {
@@ -1094,3 +1094,357 @@ fn bodyspec_carry_calculates_correctly() {
     }
 }
 
+
+#[test]
+fn partspec_damage_multiplier_calculates_correctly() {
+    // Only boosted Tough parts reduce the damage they absorb
+    for part in KNOWN_PARTS {
+        let partspec = PartSpec::new_unboosted_part(part);
+        assert_eq!(1.0, partspec.get_damage_multiplier(), "PartSpec: {:?}", partspec);
+
+        for boost in AbstractBoost::boosts_for_part(&part) {
+            let partspec = PartSpec::new_boosted_part(part, *boost);
+            let expected = match boost {
+                AbstractBoost::T1Tough => boost::T1_TOUGH_MULTIPLIER,
+                AbstractBoost::T2Tough => boost::T2_TOUGH_MULTIPLIER,
+                AbstractBoost::T3Tough => boost::T3_TOUGH_MULTIPLIER,
+                _ => 1.0,
+            };
+            assert_eq!(expected, partspec.get_damage_multiplier(), "PartSpec: {:?}", partspec);
+        }
+    }
+}
+
+#[test]
+fn bodyspec_apply_damage_destroys_unboosted_parts_front_to_back() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2A2H2M").unwrap();
+
+    // Partially damaging the first part
+    let damaged = bodyspec.apply_damage(50);
+    assert_eq!(550, damaged.hits());
+    let hits: Vec<u32> = damaged.part_specs().iter().map(|p| p.hits).collect();
+    assert_eq!(vec![50, 100, 100, 100, 100, 100], hits);
+
+    // Destroying the Attack parts and partially damaging a Heal part
+    let damaged = bodyspec.apply_damage(250);
+    let hits: Vec<u32> = damaged.part_specs().iter().map(|p| p.hits).collect();
+    assert_eq!(vec![0, 0, 50, 100, 100, 100], hits);
+    assert_eq!(0, damaged.attack_damage());
+    assert_eq!(2 * screeps::HEAL_POWER, damaged.heal_amount());
+
+    // Damage is cumulative across calls
+    let damaged_twice = bodyspec.apply_damage(100).apply_damage(150);
+    assert_eq!(damaged, damaged_twice);
+}
+
+#[test]
+fn bodyspec_apply_damage_reduces_damage_with_boosted_tough_parts() {
+    for (boost, multiplier) in std::iter::zip(boost::TOUGH_BOOSTS, [boost::T1_TOUGH_MULTIPLIER, boost::T2_TOUGH_MULTIPLIER, boost::T3_TOUGH_MULTIPLIER]) {
+        let tough = PartSpec::new_boosted_part(Part::Tough, boost);
+        let m = PartSpec::new_unboosted_part(Part::Move);
+        let bodyspec = BodySpec::new(&[tough, m]);
+
+        // Damage fully absorbed by the Tough part is reduced by the boost multiplier
+        let expected_damage = 100 - (100.0 * (1.0 - multiplier)).round() as u32;
+        assert_eq!(expected_damage, bodyspec.damage_taken(100));
+
+        let damaged = bodyspec.apply_damage(100);
+        assert_eq!(100 - expected_damage, damaged.part_specs()[0].hits);
+        assert_eq!(100, damaged.part_specs()[1].hits);
+    }
+}
+
+#[test]
+fn bodyspec_apply_damage_carries_fractional_overflow_across_parts() {
+    let tough = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T1Tough);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    let bodyspec = BodySpec::new(&[tough, tough, m]);
+
+    // Each T1 Tough part absorbs 142.857 raw damage, reducing it by 42.857; the fractional
+    // reductions are summed before rounding, so 300 raw damage becomes 300 - 86 = 214
+    assert_eq!(214, bodyspec.damage_taken(300));
+
+    let damaged = bodyspec.apply_damage(300);
+    let hits: Vec<u32> = damaged.part_specs().iter().map(|p| p.hits).collect();
+    assert_eq!(vec![0, 0, 86], hits);
+}
+
+#[test]
+fn bodyspec_apply_damage_kills_creep_on_overkill() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("5T5M").unwrap();
+    for amount in [1000, 1001, u32::MAX] {
+        let damaged = bodyspec.apply_damage(amount);
+        assert_eq!(0, damaged.hits());
+        assert_eq!(10, damaged.part_specs().len());
+        assert!(damaged.part_specs().iter().all(|p| !p.is_active()));
+    }
+}
+
+#[test]
+fn bodyspec_apply_heal_restores_parts_back_to_front() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2A2H2M").unwrap();
+    let damaged = bodyspec.apply_damage(350);
+    let hits: Vec<u32> = damaged.part_specs().iter().map(|p| p.hits).collect();
+    assert_eq!(vec![0, 0, 0, 50, 100, 100], hits);
+
+    // Partially healing only restores the rearmost damaged parts
+    let healed = damaged.apply_heal(120);
+    let hits: Vec<u32> = healed.part_specs().iter().map(|p| p.hits).collect();
+    assert_eq!(vec![0, 0, 70, 100, 100, 100], hits);
+    assert_eq!(0, healed.attack_damage());
+    assert_eq!(2 * screeps::HEAL_POWER, healed.heal_amount());
+
+    // Healing is cumulative across calls
+    let healed_twice = damaged.apply_heal(60).apply_heal(60);
+    assert_eq!(healed, healed_twice);
+
+    // Healing is capped at the maximum hits of the body
+    for amount in [350, 1000, u32::MAX] {
+        assert_eq!(bodyspec, damaged.apply_heal(amount));
+    }
+}
+
+#[test]
+fn bodyspec_apply_heal_does_not_revive_dead_creeps() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2H2M").unwrap();
+    let dead = bodyspec.apply_damage(400);
+    assert_eq!(0, dead.hits());
+    assert_eq!(dead, dead.apply_heal(100));
+    assert_eq!(dead, dead.apply_damage_and_heal(0, 100));
+}
+
+#[test]
+fn bodyspec_apply_damage_and_heal_matches_engine_tick_order() {
+    let tough = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    let h = PartSpec::new_unboosted_part(Part::Heal);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    let bodyspec = BodySpec::new(&[tough, h, m]);
+
+    // Damage is reduced by the Tough part before healing is applied
+    let result = bodyspec.apply_damage_and_heal(100, 12);
+    assert_eq!(282, result.hits());
+    assert_eq!(82, result.part_specs()[0].hits);
+
+    // Healing more than the damage taken leaves the creep at full health
+    let result = bodyspec.apply_damage_and_heal(100, 100);
+    assert_eq!(bodyspec, result);
+
+    // A heal in the same tick can save a creep from otherwise lethal damage
+    // (550 raw damage is reduced by 233 to 317, which is more than the creep's 300 hits)
+    assert_eq!(0, bodyspec.apply_damage(550).hits());
+    let result = bodyspec.apply_damage_and_heal(550, 100);
+    assert_eq!(83, result.hits());
+
+    // Damage exactly matching the creep's hits plus healing kills it
+    let result = bodyspec.apply_damage_and_heal(550, 17);
+    assert_eq!(0, result.hits());
+}
+
+#[test]
+fn partspec_claim_amounts_calculate_correctly() {
+    for part in KNOWN_PARTS {
+        let p = PartSpec::new_unboosted_part(part);
+        if part == Part::Claim {
+            assert_eq!(screeps::CONTROLLER_RESERVE, p.get_reserve_controller_amount());
+            assert_eq!(screeps::CONTROLLER_RESERVE, p.get_attack_controller_reservation_reduction());
+            assert_eq!(screeps::CONTROLLER_CLAIM_DOWNGRADE, p.get_attack_controller_downgrade_reduction());
+        } else {
+            assert_eq!(0, p.get_reserve_controller_amount());
+            assert_eq!(0, p.get_attack_controller_reservation_reduction());
+            assert_eq!(0, p.get_attack_controller_downgrade_reduction());
+        }
+    }
+}
+
+#[test]
+fn bodyspec_claim_amounts_only_count_active_claim_parts() {
+    let l = PartSpec::new_unboosted_part(Part::Claim);
+    let dead_l = PartSpec::new(Part::Claim, 0, None);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+
+    let bodyspec = BodySpec::new(&[dead_l, l, l, l, m, m, m, m]);
+    assert_eq!(3 * screeps::CONTROLLER_RESERVE, bodyspec.reserve_controller_amount());
+    assert_eq!(3 * screeps::CONTROLLER_RESERVE, bodyspec.attack_controller_reservation_reduction());
+    assert_eq!(3 * screeps::CONTROLLER_CLAIM_DOWNGRADE, bodyspec.attack_controller_downgrade_reduction());
+    assert!(bodyspec.can_claim());
+
+    let bodyspec = BodySpec::new(&[dead_l, dead_l, m]);
+    assert_eq!(0, bodyspec.reserve_controller_amount());
+    assert_eq!(0, bodyspec.attack_controller_reservation_reduction());
+    assert_eq!(0, bodyspec.attack_controller_downgrade_reduction());
+    assert!(!bodyspec.can_claim());
+
+    let bodyspec = body_generation::generate_bodyspec_from_string("10W10C10M").unwrap();
+    assert_eq!(0, bodyspec.reserve_controller_amount());
+    assert!(!bodyspec.can_claim());
+}
+
+#[test]
+fn bodyspec_lifetime_depends_on_claim_parts() {
+    for body_str in ["1M", "10W10C10M", "1A1R1H1T1M"] {
+        let bodyspec = body_generation::generate_bodyspec_from_string(body_str).unwrap();
+        assert_eq!(screeps::CREEP_LIFE_TIME, bodyspec.lifetime());
+    }
+
+    for body_str in ["1L1M", "2L2M", "1W1C1L1M"] {
+        let bodyspec = body_generation::generate_bodyspec_from_string(body_str).unwrap();
+        assert_eq!(screeps::CREEP_CLAIM_LIFE_TIME, bodyspec.lifetime());
+    }
+
+    // The lifetime is fixed at spawn time, so damaged Claim parts still count
+    let dead_l = PartSpec::new(Part::Claim, 0, None);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    assert_eq!(screeps::CREEP_CLAIM_LIFE_TIME, BodySpec::new(&[dead_l, m]).lifetime());
+}
+
+#[test]
+fn bodyspec_lifetime_totals_calculate_correctly() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("5W5C5M").unwrap();
+    assert_eq!(5 * screeps::HARVEST_POWER * screeps::CREEP_LIFE_TIME, bodyspec.lifetime_harvest_energy_amount());
+    assert_float_eq::assert_f32_near!((5 * screeps::BUILD_POWER * screeps::CREEP_LIFE_TIME) as f32, bodyspec.lifetime_build_amount());
+
+    for (round_trip_ticks, round_trips) in [(1, 1500), (50, 30), (149, 10), (150, 10), (151, 9), (1500, 1), (1501, 0), (0, 0)] {
+        assert_eq!(round_trips * 5 * screeps::CARRY_CAPACITY, bodyspec.lifetime_carry_throughput(round_trip_ticks));
+    }
+
+    // Claim parts shorten the lifetime, and with it the lifetime totals
+    let bodyspec = body_generation::generate_bodyspec_from_string("1W1C1L3M").unwrap();
+    assert_eq!(screeps::HARVEST_POWER * screeps::CREEP_CLAIM_LIFE_TIME, bodyspec.lifetime_harvest_energy_amount());
+    assert_float_eq::assert_f32_near!((screeps::BUILD_POWER * screeps::CREEP_CLAIM_LIFE_TIME) as f32, bodyspec.lifetime_build_amount());
+    assert_eq!(6 * screeps::CARRY_CAPACITY, bodyspec.lifetime_carry_throughput(100));
+}
+
+#[test]
+fn bodyspec_boost_costs_unboosted_body_is_free() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("10W10C10M").unwrap();
+    assert_eq!(boost::bom::ReactionBillOfMaterials::new(), bodyspec.boost_compounds_needed());
+    assert_eq!(0, bodyspec.boost_energy_needed());
+}
+
+#[test]
+fn bodyspec_boost_costs_calculate_correctly_for_every_boost() {
+    for boost in boost::T1_BOOSTS.into_iter().chain(boost::T2_BOOSTS).chain(boost::T3_BOOSTS) {
+        let boosted = PartSpec::new_boosted_part(AbstractBoost::part_for_boost(&boost), boost);
+        let m = PartSpec::new_unboosted_part(Part::Move);
+        for num_parts in [1, 2, 25] {
+            let mut body = vec![boosted; num_parts];
+            body.push(m);
+            let bodyspec = BodySpec::new(&body);
+
+            let bom = bodyspec.boost_compounds_needed();
+            let resource = boost.associated_resource();
+            assert_eq!(num_parts as u32 * screeps::LAB_BOOST_MINERAL, bom.resource_amount(&resource));
+            assert_eq!(num_parts as u32 * screeps::LAB_BOOST_MINERAL, bom.iter().map(|(_, amount)| amount).sum::<u32>());
+            assert_eq!(num_parts as u32 * screeps::LAB_BOOST_ENERGY, bodyspec.boost_energy_needed());
+        }
+    }
+}
+
+#[test]
+fn bodyspec_boost_costs_group_compounds_by_resource() {
+    let t3_tough = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    let t3_ranged = PartSpec::new_boosted_part(Part::RangedAttack, AbstractBoost::T3RangedAttack);
+    let t3_heal = PartSpec::new_boosted_part(Part::Heal, AbstractBoost::T3Heal);
+    let t2_move = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T2Move);
+
+    // Damaged and destroyed parts still had to be boosted
+    let dead_t3_tough = PartSpec::new(Part::Tough, 0, Some(AbstractBoost::T3Tough));
+
+    let mut body = vec![dead_t3_tough, t3_tough, t3_tough];
+    body.extend([t3_ranged; 10]);
+    body.extend([t3_heal; 5]);
+    body.extend([t2_move; 6]);
+    let bodyspec = BodySpec::new(&body);
+
+    let mut expected = boost::bom::ReactionBillOfMaterials::new();
+    expected.add_resource(&screeps::ResourceType::CatalyzedGhodiumAlkalide, 3 * screeps::LAB_BOOST_MINERAL);
+    expected.add_resource(&screeps::ResourceType::CatalyzedKeaniumAlkalide, 10 * screeps::LAB_BOOST_MINERAL);
+    expected.add_resource(&screeps::ResourceType::CatalyzedLemergiumAlkalide, 5 * screeps::LAB_BOOST_MINERAL);
+    expected.add_resource(&screeps::ResourceType::ZynthiumAlkalide, 6 * screeps::LAB_BOOST_MINERAL);
+    assert_eq!(expected, bodyspec.boost_compounds_needed());
+    assert_eq!(24 * screeps::LAB_BOOST_ENERGY, bodyspec.boost_energy_needed());
+}
+
+#[test]
+fn bodyspec_unboost_returns_nothing_for_unboosted_body() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("10W10C10M").unwrap();
+    assert_eq!(boost::bom::ReactionBillOfMaterials::new(), bodyspec.unboost_compounds_returned());
+    assert_eq!(0, bodyspec.unboost_energy_returned());
+    assert_eq!(0, bodyspec.unboost_lab_cooldown());
+}
+
+#[test]
+fn bodyspec_unboost_calculates_correctly_for_every_boost() {
+    for boost in boost::T1_BOOSTS.into_iter().chain(boost::T2_BOOSTS).chain(boost::T3_BOOSTS) {
+        let boosted = PartSpec::new_boosted_part(AbstractBoost::part_for_boost(&boost), boost);
+        let m = PartSpec::new_unboosted_part(Part::Move);
+        for num_parts in [1, 2, 25] {
+            let mut body = vec![boosted; num_parts];
+            body.push(m);
+            let bodyspec = BodySpec::new(&body);
+
+            let bom = bodyspec.unboost_compounds_returned();
+            let resource = boost.associated_resource();
+            assert_eq!(num_parts as u32 * screeps::LAB_UNBOOST_MINERAL, bom.resource_amount(&resource));
+            assert_eq!(num_parts as u32 * screeps::LAB_UNBOOST_MINERAL, bom.iter().map(|(_, amount)| amount).sum::<u32>());
+            assert_eq!(num_parts as u32 * screeps::LAB_UNBOOST_ENERGY, bodyspec.unboost_energy_returned());
+
+            // Unboosting returns exactly half of the compounds used to boost the creep
+            assert_eq!(bom.resource_amount(&resource) * 2, bodyspec.boost_compounds_needed().resource_amount(&resource));
+
+            // The cooldown is the time needed to react the returned compounds from scratch
+            let reactions = num_parts as u32 * screeps::LAB_UNBOOST_MINERAL / screeps::LAB_REACTION_AMOUNT;
+            assert_eq!(reactions * boost.reaction_chain_time(), bodyspec.unboost_lab_cooldown());
+            if !boost::T1_BOOSTS.contains(&boost) {
+                assert!(boost.reaction_chain_time() > boost.reaction_time());
+            }
+        }
+    }
+}
+
+#[test]
+fn bodyspec_unboost_cooldown_sums_mixed_boosts() {
+    let t1_move = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T1Move);
+    let t3_tough = PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough);
+    let dead_t3_tough = PartSpec::new(Part::Tough, 0, Some(AbstractBoost::T3Tough));
+    let h = PartSpec::new_unboosted_part(Part::Heal);
+    let bodyspec = BodySpec::new(&[dead_t3_tough, t3_tough, h, t1_move]);
+
+    // ZO is a single 10 tick reaction, while XGHO2 needs its whole chain, 3 reactions per part
+    let t3_tough_chain_time = AbstractBoost::T3Tough.reaction_chain_time();
+    assert!(t3_tough_chain_time > AbstractBoost::T3Tough.reaction_time());
+    assert_eq!(3 * 10 + 2 * 3 * t3_tough_chain_time, bodyspec.unboost_lab_cooldown());
+
+    let bom = bodyspec.unboost_compounds_returned();
+    assert_eq!(2 * screeps::LAB_UNBOOST_MINERAL, bom.resource_amount(&screeps::ResourceType::CatalyzedGhodiumAlkalide));
+    assert_eq!(screeps::LAB_UNBOOST_MINERAL, bom.resource_amount(&screeps::ResourceType::ZynthiumOxide));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn partspec_serializes_to_documented_representation() {
+    let part = bodyspec::PartSpec::new_boosted_part(Part::Work, AbstractBoost::T3Harvest);
+    let json = serde_json::to_string(&part).unwrap();
+    assert_eq!(r#"{"part":"work","hits":100,"boost":"T3Harvest"}"#, json);
+    assert_eq!(part, serde_json::from_str(&json).unwrap());
+
+    let part = bodyspec::PartSpec::new(Part::Move, 40, None);
+    let json = serde_json::to_string(&part).unwrap();
+    assert_eq!(r#"{"part":"move","hits":40,"boost":null}"#, json);
+    assert_eq!(part, serde_json::from_str(&json).unwrap());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn bodyspec_serializes_as_sequence_of_partspecs() {
+    let bodyspec = bodyspec::BodySpec::new(&[
+        bodyspec::PartSpec::new_boosted_part(Part::Tough, AbstractBoost::T3Tough),
+        bodyspec::PartSpec::new_unboosted_part(Part::Move),
+    ]);
+    let json = serde_json::to_string(&bodyspec).unwrap();
+    assert_eq!(r#"[{"part":"tough","hits":100,"boost":"T3Tough"},{"part":"move","hits":100,"boost":null}]"#, json);
+    assert_eq!(bodyspec, serde_json::from_str(&json).unwrap());
+
+    let result: Result<bodyspec::BodySpec, _> = serde_json::from_str(r#"[{"part":"wings","hits":100,"boost":null}]"#);
+    assert!(result.is_err());
+}
@@ -1,7 +1,9 @@
+#[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
 /// Represents the choice of what boosts, if any, are desired when constructing a creep body.
-#[derive(Debug, Hash, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Hash, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoostTierChoice {
     /// No boosts should be chosen
     NoBoosts,
@@ -46,7 +48,8 @@ pub enum BoostTierChoice {
 /// // aren't uniform
 /// const up_to_t3_creeps_allow_nonuniform: BoostSelectionConfig = BoostSelectionConfig::new(BoostTierChoice::UpToT3, true);
 /// ```
-#[derive(Debug, Hash, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Hash, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BoostSelectionConfig {
     /// How to select the tier of any particular boost
     pub boost_tier_choice: BoostTierChoice,
@@ -15,8 +15,13 @@ mod bodyspec;
 mod body_generation;
 mod body_calculation_helpers;
 mod boost_selection_config;
+mod body_ordering_helpers;
+mod body_scaling_helpers;
+mod body_planning_helpers;
+mod movement;
 
 pub use bodyspec::*;
+pub use movement::{MovementSimulation, TileType};
 
 /// Provides helpers for generating bodies from strings.
 ///
@@ -53,9 +58,16 @@ pub use bodyspec::*;
 pub mod body_specification {
     pub use super::body_generation::{
         GenerateBodyError,
+        BodyStringParseError,
+        BodyStringToken,
         generate_body_from_string,
         generate_bodyspec_from_string,
+        generate_body_from_string_strict,
+        generate_bodyspec_from_string_strict,
         convert_character_to_part,
+        convert_part_to_character,
+        generate_string_from_body,
+        generate_string_from_bodyspec,
         parse_part_groups,
     };
 }
@@ -66,9 +78,35 @@ pub mod body_calculations {
     pub use super::body_calculation_helpers::*;
 }
 
+/// Provides helpers for ordering the parts of a body to suit what the creep will be doing.
+pub mod body_ordering {
+    pub use super::body_ordering_helpers::*;
+}
+
+/// Provides helpers for scaling a body to fit the energy available for spawning it.
+pub mod body_scaling {
+    pub use super::body_scaling_helpers::*;
+}
+
+/// Provides helpers for planning a complete body that meets several capability targets at once.
+pub mod body_planning {
+    pub use super::body_planning_helpers::*;
+}
+
 #[cfg(test)]
 mod body_generation_tests;
 
 #[cfg(test)]
 mod bodyspec_tests;
 
+#[cfg(test)]
+mod body_ordering_helpers_tests;
+
+#[cfg(test)]
+mod movement_tests;
+
+#[cfg(test)]
+mod body_scaling_helpers_tests;
+
+#[cfg(test)]
+mod body_planning_helpers_tests;
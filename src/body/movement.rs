@@ -0,0 +1,187 @@
+//! Implements tick-by-tick movement simulation for a BodySpec travelling along a path.
+
+use screeps::Part;
+use screeps::constants::extra::{
+    MOVE_COST_SWAMP,
+    MOVE_COST_PLAIN,
+    MOVE_COST_ROAD,
+};
+
+use super::bodyspec::BodySpec;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// Represents the kind of tile a creep is moving onto, as far as fatigue is concerned.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TileType {
+    /// A plains tile without a road
+    Plain,
+
+    /// A swamp tile without a road
+    Swamp,
+
+    /// Any tile with a road on it, regardless of the underlying terrain
+    Road,
+}
+
+impl TileType {
+    /// The fatigue cost per fatigue-generating part of moving onto this kind of tile.
+    ///
+    /// ```rust
+    /// use screeps::constants::extra::{MOVE_COST_PLAIN, MOVE_COST_ROAD, MOVE_COST_SWAMP};
+    /// use screeps_body_utils::body::TileType;
+    ///
+    /// assert_eq!(MOVE_COST_PLAIN, TileType::Plain.move_cost());
+    /// assert_eq!(MOVE_COST_SWAMP, TileType::Swamp.move_cost());
+    /// assert_eq!(MOVE_COST_ROAD, TileType::Road.move_cost());
+    /// ```
+    pub const fn move_cost(&self) -> u32 {
+        match self {
+            TileType::Plain => MOVE_COST_PLAIN,
+            TileType::Swamp => MOVE_COST_SWAMP,
+            TileType::Road => MOVE_COST_ROAD,
+        }
+    }
+}
+
+/// The result of simulating a creep's movement along a path of tiles.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MovementSimulation {
+    /// The creep's fatigue at the end of each tick, after fatigue reduction has been applied.
+    pub fatigue_trace: Vec<u32>,
+
+    /// The number of ticks needed for the creep to reach the final tile of the path.
+    pub total_ticks: u32,
+}
+
+impl BodySpec {
+    /// Simulates this body moving along the provided path, one tile per successful move.
+    ///
+    /// The creep starts with no fatigue. Each tick, the creep moves onto the next tile if its
+    /// fatigue is 0, generating fatigue as per
+    /// [get_fatigue_generation](super::PartSpec::get_fatigue_generation). At the end of every
+    /// tick, the creep's fatigue is reduced as per
+    /// [get_fatigue_reduction](super::PartSpec::get_fatigue_reduction). This matches the order
+    /// of operations in the [engine
+    /// code](https://github.com/screeps/engine/blob/master/src/processor/intents/movement.js).
+    ///
+    /// The path should not include the tile the creep starts on. The simulation ends on the tick
+    /// the creep moves onto the final tile, so any fatigue left over from that move will show up
+    /// as the last entry in the fatigue trace.
+    ///
+    /// Returns None if the body has no active Move parts, since such a creep can't move at all.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::TileType;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    ///
+    /// // A hauler with half as many Move parts as it needs for plains
+    /// let bodyspec = generate_bodyspec_from_string("4C1M").unwrap();
+    /// let path = [TileType::Road, TileType::Road, TileType::Plain];
+    /// let simulation = bodyspec.simulate_movement(&path).unwrap();
+    ///
+    /// // Roads take 2 ticks each, and the plains tile leaves 6 fatigue behind
+    /// assert_eq!(vec![2, 0, 2, 0, 6], simulation.fatigue_trace);
+    /// assert_eq!(5, simulation.total_ticks);
+    ///
+    /// // Without any Move parts, the creep is stuck
+    /// let bodyspec = generate_bodyspec_from_string("4C").unwrap();
+    /// assert!(bodyspec.simulate_movement(&path).is_none());
+    /// ```
+    pub fn simulate_movement(&self, path: &[TileType]) -> Option<MovementSimulation> {
+        self.simulate_movement_with_fatigue_generation(path, |tile_cost| {
+            self.part_specs().iter().map(|p| p.get_fatigue_generation(tile_cost)).sum()
+        })
+    }
+
+    /// Simulates this body moving along the provided path while storing the specified amount of
+    /// resources.
+    ///
+    /// This works like [simulate_movement](BodySpec::simulate_movement), except that only the
+    /// Carry parts the engine considers non-empty generate fatigue, as per
+    /// [loaded_carry_parts](BodySpec::loaded_carry_parts). This is useful for haulers that travel
+    /// empty in one direction and full in the other.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::TileType;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    ///
+    /// let bodyspec = generate_bodyspec_from_string("4C1M").unwrap();
+    /// let path = [TileType::Plain; 2];
+    ///
+    /// // Travelling empty, the hauler moves every tick
+    /// let simulation = bodyspec.simulate_movement_with_store(&path, 0).unwrap();
+    /// assert_eq!(2, simulation.total_ticks);
+    ///
+    /// // Fully loaded, each plains tile takes 4 ticks
+    /// let simulation = bodyspec.simulate_movement_with_store(&path, 200).unwrap();
+    /// assert_eq!(5, simulation.total_ticks);
+    /// assert_eq!(bodyspec.simulate_movement(&path), Some(simulation));
+    /// ```
+    pub fn simulate_movement_with_store(&self, path: &[TileType], store_amount: u32) -> Option<MovementSimulation> {
+        self.simulate_movement_with_fatigue_generation(path, |tile_cost| {
+            self.fatigue_generation_with_store(tile_cost, store_amount)
+        })
+    }
+
+    /// Calculates the net exhaustion that results from moving this body onto a tile while storing
+    /// the specified amount of resources.
+    ///
+    /// Unlike [plains_move_net_exhaustion](BodySpec::plains_move_net_exhaustion) and friends, this
+    /// doesn't assume that every active Carry part is loaded.
+    ///
+    /// ```rust
+    /// use screeps::constants::extra::MOVE_COST_PLAIN;
+    /// use screeps_body_utils::body::TileType;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    ///
+    /// let bodyspec = generate_bodyspec_from_string("4C2M").unwrap();
+    /// assert_eq!(0, bodyspec.move_net_exhaustion_with_store(TileType::Plain, 0));
+    /// assert_eq!(0, bodyspec.move_net_exhaustion_with_store(TileType::Plain, 100));
+    /// assert_eq!(MOVE_COST_PLAIN, bodyspec.move_net_exhaustion_with_store(TileType::Plain, 101));
+    /// assert_eq!(bodyspec.plains_move_net_exhaustion(), bodyspec.move_net_exhaustion_with_store(TileType::Plain, 200));
+    /// ```
+    pub fn move_net_exhaustion_with_store(&self, tile: TileType, store_amount: u32) -> u32 {
+        let fatigue_reduction: u32 = self.part_specs().iter().map(|p| p.get_fatigue_reduction()).sum();
+        self.fatigue_generation_with_store(tile.move_cost(), store_amount).saturating_sub(fatigue_reduction)
+    }
+
+    /// Calculates the fatigue generated by moving onto a tile with the specified cost, where only
+    /// the non-empty Carry parts contribute.
+    fn fatigue_generation_with_store(&self, tile_cost: u32, store_amount: u32) -> u32 {
+        let non_carry_generation: u32 = self.part_specs().iter()
+            .filter(|p| p.part != Part::Carry)
+            .map(|p| p.get_fatigue_generation(tile_cost))
+            .sum();
+        non_carry_generation + self.loaded_carry_parts(store_amount) * tile_cost
+    }
+
+    fn simulate_movement_with_fatigue_generation(&self, path: &[TileType], fatigue_generation: impl Fn(u32) -> u32) -> Option<MovementSimulation> {
+        let fatigue_reduction: u32 = self.part_specs().iter().map(|p| p.get_fatigue_reduction()).sum();
+        if fatigue_reduction == 0 {
+            return None;
+        }
+
+        let mut fatigue: u32 = 0;
+        let mut fatigue_trace = Vec::new();
+        for tile in path {
+            // Wait out any fatigue from the previous move
+            while fatigue > 0 {
+                fatigue = fatigue.saturating_sub(fatigue_reduction);
+                fatigue_trace.push(fatigue);
+            }
+
+            fatigue = fatigue_generation(tile.move_cost()).saturating_sub(fatigue_reduction);
+            fatigue_trace.push(fatigue);
+        }
+
+        let total_ticks = fatigue_trace.len() as u32;
+        Some(MovementSimulation {
+            fatigue_trace,
+            total_ticks,
+        })
+    }
+}
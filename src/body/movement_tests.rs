@@ -0,0 +1,164 @@
+use screeps::Part;
+use screeps::constants::extra::{
+    MOVE_COST_SWAMP,
+    MOVE_COST_PLAIN,
+    MOVE_COST_ROAD,
+};
+
+use super::body_generation;
+use crate::body::{BodySpec, PartSpec, TileType};
+use crate::boost::AbstractBoost;
+
+
+#[test]
+fn tile_type_move_cost_matches_constants() {
+    assert_eq!(MOVE_COST_PLAIN, TileType::Plain.move_cost());
+    assert_eq!(MOVE_COST_SWAMP, TileType::Swamp.move_cost());
+    assert_eq!(MOVE_COST_ROAD, TileType::Road.move_cost());
+}
+
+#[test]
+fn simulate_movement_empty_path_takes_no_ticks() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("1W1M").unwrap();
+    let simulation = bodyspec.simulate_movement(&[]).unwrap();
+    assert!(simulation.fatigue_trace.is_empty());
+    assert_eq!(0, simulation.total_ticks);
+}
+
+#[test]
+fn simulate_movement_fully_moved_body_moves_every_tick() {
+    let path = [TileType::Plain, TileType::Road, TileType::Plain, TileType::Road];
+    for body_str in ["1W1M", "5W5M", "1M", "25C25M"] {
+        let bodyspec = body_generation::generate_bodyspec_from_string(body_str).unwrap();
+        let simulation = bodyspec.simulate_movement(&path).unwrap();
+        assert_eq!(vec![0; 4], simulation.fatigue_trace);
+        assert_eq!(4, simulation.total_ticks);
+    }
+}
+
+#[test]
+fn simulate_movement_under_moved_body_waits_out_fatigue() {
+    // 2 fatigue-generating parts per Move part: 2 ticks per plains tile, 1 per road tile
+    let bodyspec = body_generation::generate_bodyspec_from_string("4W2M").unwrap();
+    let path = [TileType::Plain, TileType::Plain, TileType::Road, TileType::Road];
+    let simulation = bodyspec.simulate_movement(&path).unwrap();
+    assert_eq!(vec![4, 0, 4, 0, 0, 0], simulation.fatigue_trace);
+    assert_eq!(6, simulation.total_ticks);
+
+    // Swamps generate 10 fatigue per part, which takes a single Move part 20 ticks to clear
+    let bodyspec = body_generation::generate_bodyspec_from_string("2W1M").unwrap();
+    let simulation = bodyspec.simulate_movement(&[TileType::Swamp, TileType::Road]).unwrap();
+    let mut expected_trace: Vec<u32> = (0..10).rev().map(|i| i * 2).collect();
+    expected_trace.push(0);
+    assert_eq!(expected_trace, simulation.fatigue_trace);
+    assert_eq!(11, simulation.total_ticks);
+}
+
+#[test]
+fn simulate_movement_uses_boosted_move_parts() {
+    let w = PartSpec::new_unboosted_part(Part::Work);
+    let m = PartSpec::new_boosted_part(Part::Move, AbstractBoost::T3Move);
+    let bodyspec = BodySpec::new(&[w, w, w, w, m]);
+
+    // A T3 Move part reduces 8 fatigue per tick, enough for 4 parts on plains
+    let path = [TileType::Plain; 3];
+    let simulation = bodyspec.simulate_movement(&path).unwrap();
+    assert_eq!(vec![0, 0, 0], simulation.fatigue_trace);
+
+    // But on swamps, the 40 fatigue takes 5 ticks to clear
+    let simulation = bodyspec.simulate_movement(&[TileType::Swamp, TileType::Swamp]).unwrap();
+    assert_eq!(vec![32, 24, 16, 8, 0, 32], simulation.fatigue_trace);
+    assert_eq!(6, simulation.total_ticks);
+}
+
+#[test]
+fn simulate_movement_ignores_inactive_parts() {
+    let w = PartSpec::new_unboosted_part(Part::Work);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+    let dead_c = PartSpec::new(Part::Carry, 0, None);
+    let dead_m = PartSpec::new(Part::Move, 0, None);
+
+    // Dead Carry parts don't generate fatigue
+    let bodyspec = BodySpec::new(&[dead_c, dead_c, w, m]);
+    let simulation = bodyspec.simulate_movement(&[TileType::Plain; 2]).unwrap();
+    assert_eq!(vec![0, 0], simulation.fatigue_trace);
+
+    // Dead Move parts don't reduce fatigue
+    let bodyspec = BodySpec::new(&[w, w, dead_m, m]);
+    let simulation = bodyspec.simulate_movement(&[TileType::Plain; 2]).unwrap();
+    assert_eq!(vec![2, 0, 2], simulation.fatigue_trace);
+
+    // And a creep with only dead Move parts can't move at all
+    let bodyspec = BodySpec::new(&[w, dead_m]);
+    assert!(bodyspec.simulate_movement(&[TileType::Road]).is_none());
+}
+
+#[test]
+fn loaded_carry_parts_fills_from_back_of_body() {
+    let c = PartSpec::new_unboosted_part(Part::Carry);
+    let t3_c = PartSpec::new_boosted_part(Part::Carry, AbstractBoost::T3Carry);
+    let dead_c = PartSpec::new(Part::Carry, 0, None);
+    let m = PartSpec::new_unboosted_part(Part::Move);
+
+    // The boosted Carry part at the back holds 200 resources before the next part is used
+    let bodyspec = BodySpec::new(&[c, m, c, t3_c]);
+    assert_eq!(0, bodyspec.loaded_carry_parts(0));
+    assert_eq!(1, bodyspec.loaded_carry_parts(200));
+    assert_eq!(2, bodyspec.loaded_carry_parts(201));
+    assert_eq!(2, bodyspec.loaded_carry_parts(250));
+    assert_eq!(3, bodyspec.loaded_carry_parts(251));
+    assert_eq!(3, bodyspec.loaded_carry_parts(u32::MAX));
+
+    // Dead Carry parts can't hold anything
+    let bodyspec = BodySpec::new(&[c, dead_c, m]);
+    assert_eq!(1, bodyspec.loaded_carry_parts(50));
+    assert_eq!(1, bodyspec.loaded_carry_parts(100));
+}
+
+#[test]
+fn move_net_exhaustion_with_store_only_counts_loaded_carry_parts() {
+    let bodyspec = body_generation::generate_bodyspec_from_string("2W10C2M").unwrap();
+
+    // Work parts always generate fatigue
+    assert_eq!(0, bodyspec.move_net_exhaustion_with_store(TileType::Plain, 0));
+    assert_eq!(MOVE_COST_SWAMP * 2 - 4, bodyspec.move_net_exhaustion_with_store(TileType::Swamp, 0));
+
+    for (store_amount, loaded_parts) in [(1, 1), (50, 1), (51, 2), (499, 10), (500, 10)] {
+        assert_eq!(
+            (2 + loaded_parts) * MOVE_COST_PLAIN - 4,
+            bodyspec.move_net_exhaustion_with_store(TileType::Plain, store_amount)
+        );
+        assert_eq!(
+            ((2 + loaded_parts) * MOVE_COST_ROAD).saturating_sub(4),
+            bodyspec.move_net_exhaustion_with_store(TileType::Road, store_amount)
+        );
+    }
+
+    // A full store matches the existing helpers, which assume every Carry part is loaded
+    assert_eq!(bodyspec.plains_move_net_exhaustion(), bodyspec.move_net_exhaustion_with_store(TileType::Plain, 500));
+    assert_eq!(bodyspec.swamp_move_net_exhaustion(), bodyspec.move_net_exhaustion_with_store(TileType::Swamp, 500));
+    assert_eq!(bodyspec.road_move_net_exhaustion(), bodyspec.move_net_exhaustion_with_store(TileType::Road, 500));
+}
+
+#[test]
+fn simulate_movement_with_store_handles_round_trip() {
+    // A road hauler sized for a full load moves every tick both ways
+    let bodyspec = body_generation::generate_bodyspec_from_string("10C5M").unwrap();
+    let path = [TileType::Road; 10];
+    for store_amount in [0, 250, 500] {
+        let simulation = bodyspec.simulate_movement_with_store(&path, store_amount).unwrap();
+        assert_eq!(10, simulation.total_ticks);
+    }
+
+    // The same hauler on plains is only slowed down on the loaded leg
+    let path = [TileType::Plain; 10];
+    let empty = bodyspec.simulate_movement_with_store(&path, 0).unwrap();
+    assert_eq!(10, empty.total_ticks);
+    let full = bodyspec.simulate_movement_with_store(&path, 500).unwrap();
+    assert_eq!(19, full.total_ticks);
+    assert_eq!(bodyspec.simulate_movement(&path), Some(full));
+
+    // A half load fills exactly as many Carry parts as there are Move parts
+    let half = bodyspec.simulate_movement_with_store(&path, 250).unwrap();
+    assert_eq!(10, half.total_ticks);
+}
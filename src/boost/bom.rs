@@ -16,6 +16,9 @@ use crate::constants::resources::{
 use crate::boost::reaction::Reaction;
 use crate::helpers::functions::const_min_u32;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// Encapsulates a list of minerals and mineral compounds and the amounts of each.
 ///
 /// This can be used to determine what resources are needed to get a certain quantity of boosts,
@@ -23,7 +26,11 @@ use crate::helpers::functions::const_min_u32;
 ///
 /// Most methods are const, so that the calculations can be done at compile time instead of runtime
 /// if defining hardcoded bills of materials for specific boosts.
+///
+/// With the `serde` feature enabled, a bill of materials is represented as a map from each field
+/// name (i.e. `"catalyzed_ghodium_alkalide"`) to its amount. Missing fields default to 0.
 #[derive(Debug, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct ReactionBillOfMaterials {
     // Raw minerals
     pub hydrogen: u32,
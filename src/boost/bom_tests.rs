@@ -108,3 +108,21 @@ fn bom_reduce_all_resources_to_base_materials() {
 
 
 
+
+#[cfg(feature = "serde")]
+#[test]
+fn bom_serializes_as_map_of_field_names() {
+    let mut bom = ReactionBillOfMaterials::new();
+    bom.add_resource(&ResourceType::Hydrogen, 50);
+    bom.add_resource(&ResourceType::CatalyzedGhodiumAlkalide, 30);
+
+    let value = serde_json::to_value(bom).unwrap();
+    assert_eq!(50, value["hydrogen"]);
+    assert_eq!(30, value["catalyzed_ghodium_alkalide"]);
+    assert_eq!(0, value["oxygen"]);
+    assert_eq!(bom, serde_json::from_value(value).unwrap());
+
+    // Missing fields default to 0
+    let sparse_bom: ReactionBillOfMaterials = serde_json::from_str(r#"{"hydrogen":50,"catalyzed_ghodium_alkalide":30}"#).unwrap();
+    assert_eq!(bom, sparse_bom);
+}
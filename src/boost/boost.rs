@@ -1,13 +1,22 @@
 //! Convenience abstraction layer for working with Boosts.
 
+use std::str::FromStr;
+
 use screeps::constants::ResourceType;
 use screeps::constants::Boost;
 use screeps::constants::Part;
 use crate::boost::reaction::Reaction;
 use crate::boost::reaction_chains;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// Encapsulates the concept of a Creep boost, regardless of the actual numbers involved.
+///
+/// With the `serde` feature enabled, boosts are represented by their variant name, i.e.
+/// `"T3Harvest"`, matching their [FromStr] implementation.
 #[derive(Debug, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AbstractBoost {
     // Work
     T1Harvest,
@@ -437,7 +446,11 @@ pub const T3_TOUGH_MULTIPLIER: f32 = 0.3;
 ///
 /// While these are primarily actions (build, repair, etc.), they also cover things such as store
 /// capacity, fatigue reduction, and damage reduction.
+///
+/// With the `serde` feature enabled, categories are represented by their variant name, i.e.
+/// `"HarvestEnergy"`.
 #[derive(Debug, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoostCategory {
     HarvestEnergy,
     HarvestMineral,
@@ -972,6 +985,48 @@ impl TryFrom<ResourceType> for AbstractBoost {
     }
 }
 
+// FromStr impl for parsing boosts from their variant names (i.e. "T3Harvest")
+impl FromStr for AbstractBoost {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use AbstractBoost::*;
+        match s {
+            "T1Harvest" => Ok(T1Harvest),
+            "T1BuildRepair" => Ok(T1BuildRepair),
+            "T1Dismantle" => Ok(T1Dismantle),
+            "T1UpgradeController" => Ok(T1UpgradeController),
+            "T2Harvest" => Ok(T2Harvest),
+            "T2BuildRepair" => Ok(T2BuildRepair),
+            "T2Dismantle" => Ok(T2Dismantle),
+            "T2UpgradeController" => Ok(T2UpgradeController),
+            "T3Harvest" => Ok(T3Harvest),
+            "T3BuildRepair" => Ok(T3BuildRepair),
+            "T3Dismantle" => Ok(T3Dismantle),
+            "T3UpgradeController" => Ok(T3UpgradeController),
+            "T1Attack" => Ok(T1Attack),
+            "T2Attack" => Ok(T2Attack),
+            "T3Attack" => Ok(T3Attack),
+            "T1RangedAttack" => Ok(T1RangedAttack),
+            "T2RangedAttack" => Ok(T2RangedAttack),
+            "T3RangedAttack" => Ok(T3RangedAttack),
+            "T1Heal" => Ok(T1Heal),
+            "T2Heal" => Ok(T2Heal),
+            "T3Heal" => Ok(T3Heal),
+            "T1Carry" => Ok(T1Carry),
+            "T2Carry" => Ok(T2Carry),
+            "T3Carry" => Ok(T3Carry),
+            "T1Move" => Ok(T1Move),
+            "T2Move" => Ok(T2Move),
+            "T3Move" => Ok(T3Move),
+            "T1Tough" => Ok(T1Tough),
+            "T2Tough" => Ok(T2Tough),
+            "T3Tough" => Ok(T3Tough),
+            _ => Err("string is not a boost name"),
+        }
+    }
+}
+
 // From impl for ResourceType (since each boost maps to a resource)
 impl From<AbstractBoost> for ResourceType {
     fn from(val: AbstractBoost) -> Self {
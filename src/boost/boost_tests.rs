@@ -99,3 +99,43 @@ fn boost_from_abstractboost_converts_correctly() {
 }
 
 
+
+#[test]
+fn abstractboost_from_str_round_trips_debug_names() {
+    for (_, abstract_boost) in abstractboost_boost_conversion_data {
+        let name = format!("{:?}", abstract_boost);
+        let res = name.parse::<AbstractBoost>();
+        assert_eq!(Ok(abstract_boost), res);
+    }
+}
+
+#[test]
+fn abstractboost_from_str_errors_for_unknown_names() {
+    for name in ["", "T4Harvest", "t3harvest", "XGH2O", "Harvest", " T3Harvest"] {
+        assert!(name.parse::<AbstractBoost>().is_err(), "Name: {}", name);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn abstractboost_serializes_as_variant_name() {
+    for boost in T1_BOOSTS.into_iter().chain(T2_BOOSTS).chain(T3_BOOSTS) {
+        let json = serde_json::to_string(&boost).unwrap();
+        assert_eq!(format!("\"{:?}\"", boost), json);
+        assert_eq!(boost, serde_json::from_str::<AbstractBoost>(&json).unwrap());
+    }
+
+    assert!(serde_json::from_str::<AbstractBoost>("\"T4Harvest\"").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn boostcategory_serializes_as_variant_name() {
+    let json = serde_json::to_string(&boost::BoostCategory::HarvestEnergy).unwrap();
+    assert_eq!("\"HarvestEnergy\"", json);
+    assert_eq!(boost::BoostCategory::HarvestEnergy, serde_json::from_str::<boost::BoostCategory>(&json).unwrap());
+
+    let json = serde_json::to_string(&boost::BoostCategory::RangedMassAttack).unwrap();
+    assert_eq!("\"RangedMassAttack\"", json);
+    assert_eq!(boost::BoostCategory::RangedMassAttack, serde_json::from_str::<boost::BoostCategory>(&json).unwrap());
+}
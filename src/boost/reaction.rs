@@ -1,11 +1,17 @@
 //! Abstraction of individual lab reactions.
 
+use std::error::Error;
+use std::fmt;
+
 use screeps::ResourceType;
 use screeps::LAB_REACTION_AMOUNT;
 
 use crate::boost::bom::ReactionBillOfMaterials;
 use crate::helpers::functions::resource_is_lab_compound;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 // Ultimately, we want a way to capture and work with the reaction chains specifically, and then
 // convert those reaction chains into a bill of materials at different levels of complexity so that
 // a bot can evaluate rooms for mineral harvesting, purchase supplies from the market, or just know
@@ -26,16 +32,50 @@ use crate::helpers::functions::resource_is_lab_compound;
 
 /// Encapsulates a specific lab reaction intent, with both the desired output and the desired
 /// amount of that output.
+///
+/// With the `serde` feature enabled, a Reaction is represented as a map with the keys `output`
+/// (the resource name used by the game, i.e. `"XGH2O"`) and `num_desired`. The output is
+/// validated when deserializing, as per [checked_new](Reaction::checked_new).
 #[derive(Debug, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "ReactionFields"))]
 pub struct Reaction {
     output: ResourceType,
     num_desired: u32,
 }
 
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ReactionValidationError {
     NotALabCompound,
 }
 
+impl fmt::Display for ReactionValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::NotALabCompound => "Reaction output is not a lab compound",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Error for ReactionValidationError {}
+
+/// The unvalidated fields of a [Reaction], used when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct ReactionFields {
+    output: ResourceType,
+    num_desired: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ReactionFields> for Reaction {
+    type Error = ReactionValidationError;
+
+    fn try_from(fields: ReactionFields) -> Result<Self, Self::Error> {
+        Reaction::checked_new(fields.output, fields.num_desired)
+    }
+}
+
 impl Reaction {
     /// Generates a new Reaction, validating that the desired output resource type is actually a
     /// lab compound.
@@ -80,3 +80,22 @@ fn reaction_reaction_chain_time_matches() {
 		assert_eq!(res, sum_times);
 	}
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn reaction_serializes_to_documented_representation() {
+    let reaction = Reaction::checked_new(ResourceType::CatalyzedGhodiumAcid, 3000).ok().unwrap();
+    let json = serde_json::to_string(&reaction).unwrap();
+    assert_eq!(r#"{"output":"XGH2O","num_desired":3000}"#, json);
+    assert_eq!(reaction, serde_json::from_str(&json).unwrap());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn reaction_deserialization_rejects_non_lab_compounds() {
+    let result: Result<Reaction, _> = serde_json::from_str(r#"{"output":"energy","num_desired":3000}"#);
+    assert!(result.is_err());
+
+    let result: Result<Reaction, _> = serde_json::from_str(r#"{"output":"H","num_desired":3000}"#);
+    assert!(result.is_err());
+}
@@ -0,0 +1,245 @@
+//! Resolves a fight between two creeps, tick by tick.
+
+use screeps::constants::CREEP_LIFE_TIME;
+
+use crate::body::BodySpec;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// How far apart the two creeps in a duel are.
+///
+/// With the `serde` feature enabled, a DuelRange is represented as the variant name.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuelRange {
+    /// Adjacent, so both melee and ranged attacks can be used, and melee attacks are retaliated
+    /// against
+    Melee,
+
+    /// Within ranged attack range but not adjacent, so only ranged attacks can be used
+    Ranged,
+}
+
+/// How a creep chooses between its Attack and Heal parts each tick.
+///
+/// The engine doesn't allow a creep to both attack and heal in the same tick, though either can be
+/// combined with a ranged attack.
+///
+/// With the `serde` feature enabled, a DuelHealPolicy is represented as the variant name.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuelHealPolicy {
+    /// Attack whenever the creep has active Attack parts and is in melee range, and heal itself
+    /// otherwise
+    AttackFirst,
+
+    /// Heal itself whenever it has taken damage, and attack otherwise
+    HealWhenDamaged,
+}
+
+/// Settings for resolving a duel.
+///
+/// With the `serde` feature enabled, a DuelConfig is represented as a map with the keys `range`,
+/// `first_policy`, `second_policy`, and `max_ticks`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DuelConfig {
+    /// How far apart the creeps are for the whole duel
+    pub range: DuelRange,
+
+    /// How the first creep chooses between attacking and healing
+    pub first_policy: DuelHealPolicy,
+
+    /// How the second creep chooses between attacking and healing
+    pub second_policy: DuelHealPolicy,
+
+    /// The number of ticks after which the duel is called off if neither creep has died
+    pub max_ticks: u32,
+}
+
+impl DuelConfig {
+    pub const fn new(range: DuelRange, first_policy: DuelHealPolicy, second_policy: DuelHealPolicy, max_ticks: u32) -> DuelConfig {
+        DuelConfig {
+            range,
+            first_policy,
+            second_policy,
+            max_ticks,
+        }
+    }
+}
+
+impl Default for DuelConfig {
+    fn default() -> DuelConfig {
+        DuelConfig::new(DuelRange::Melee, DuelHealPolicy::HealWhenDamaged, DuelHealPolicy::HealWhenDamaged, CREEP_LIFE_TIME)
+    }
+}
+
+/// Which creep, if any, won a duel.
+///
+/// With the `serde` feature enabled, a DuelWinner is represented as the variant name.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuelWinner {
+    /// The second creep died while the first survived
+    First,
+
+    /// The first creep died while the second survived
+    Second,
+
+    /// Both creeps died on the same tick
+    BothDestroyed,
+
+    /// Neither creep died within the tick limit
+    Neither,
+}
+
+/// The result of a duel.
+///
+/// With the `serde` feature enabled, a DuelOutcome is represented as a map with the keys
+/// `winner`, `first_ticks_to_kill`, `second_ticks_to_kill`, `first_remaining`, and
+/// `second_remaining`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DuelOutcome {
+    /// Which creep won
+    pub winner: DuelWinner,
+
+    /// The tick on which the first creep killed the second, or None if the second creep
+    /// survived
+    pub first_ticks_to_kill: Option<u32>,
+
+    /// The tick on which the second creep killed the first, or None if the first creep survived
+    pub second_ticks_to_kill: Option<u32>,
+
+    /// The first creep's body at the end of the duel
+    pub first_remaining: BodySpec,
+
+    /// The second creep's body at the end of the duel
+    pub second_remaining: BodySpec,
+}
+
+impl DuelOutcome {
+    /// The first creep's hits at the end of the duel
+    pub fn first_hits(&self) -> u32 {
+        self.first_remaining.hits()
+    }
+
+    /// The second creep's hits at the end of the duel
+    pub fn second_hits(&self) -> u32 {
+        self.second_remaining.hits()
+    }
+}
+
+/// What a single creep does in a tick of a duel.
+struct DuelAction {
+    melee_damage: u32,
+    ranged_damage: u32,
+    heal: u32,
+}
+
+/// Decides what a creep does this tick, based on its state at the start of the tick.
+fn choose_action(bodyspec: &BodySpec, range: DuelRange, policy: DuelHealPolicy) -> DuelAction {
+    let melee_damage = match range {
+        DuelRange::Melee => bodyspec.attack_damage(),
+        DuelRange::Ranged => 0,
+    };
+    let heal = bodyspec.heal_amount();
+    let damaged = bodyspec.hits() < bodyspec.hits_max();
+
+    let heals = match policy {
+        DuelHealPolicy::AttackFirst => melee_damage == 0,
+        DuelHealPolicy::HealWhenDamaged => damaged && heal > 0,
+    };
+
+    DuelAction {
+        melee_damage: if heals { 0 } else { melee_damage },
+        ranged_damage: bodyspec.ranged_attack_damage(),
+        heal: if heals && damaged { heal } else { 0 },
+    }
+}
+
+/// Resolves a fight between two creeps, which may already be damaged.
+///
+/// Each tick, both creeps act based on their state at the start of the tick: they use their Ranged
+/// Attack parts, and either their Attack parts or their Heal parts on themselves, as per their
+/// [DuelHealPolicy]. A creep that's hit by a melee attack retaliates with its own Attack parts,
+/// even if it attacked or healed that tick. All of the damage and healing a creep receives in a
+/// tick is then applied at once, after being reduced by any boosted Tough parts, as per
+/// [BodySpec::apply_damage_and_heal].
+///
+/// The duel ends as soon as either creep dies, or once [max_ticks](DuelConfig::max_ticks) have
+/// passed. A creep that starts the duel with 0 hits loses without a fight.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::duel::{DuelConfig, DuelWinner, resolve_duel};
+///
+/// let attacker = generate_bodyspec_from_string("5M 5A").unwrap();
+/// let defender = generate_bodyspec_from_string("2M 2A").unwrap();
+///
+/// let outcome = resolve_duel(&attacker, &defender, &DuelConfig::default());
+/// assert_eq!(DuelWinner::First, outcome.winner);
+///
+/// // Both creeps attack each other, so each tick they take both the other's attack and the
+/// // retaliation against their own attack. The defender takes 300 damage on the first tick,
+/// // destroying one of its Attack parts, so the attacker takes 120 damage and then 60
+/// assert_eq!(Some(2), outcome.first_ticks_to_kill);
+/// assert_eq!(None, outcome.second_ticks_to_kill);
+/// assert_eq!(1000 - 120 - 60, outcome.first_hits());
+/// ```
+pub fn resolve_duel(first: &BodySpec, second: &BodySpec, config: &DuelConfig) -> DuelOutcome {
+    let mut first = first.clone();
+    let mut second = second.clone();
+    let mut tick = 0;
+
+    let mut first_ticks_to_kill = None;
+    let mut second_ticks_to_kill = None;
+
+    // Creeps that start out dead don't fight
+    if first.hits() > 0 && second.hits() > 0 {
+        while tick < config.max_ticks {
+            tick += 1;
+
+            let first_action = choose_action(&first, config.range, config.first_policy);
+            let second_action = choose_action(&second, config.range, config.second_policy);
+
+            // Melee attacks are retaliated against by the target's Attack parts
+            let first_retaliation = if first_action.melee_damage > 0 { second.attack_damage() } else { 0 };
+            let second_retaliation = if second_action.melee_damage > 0 { first.attack_damage() } else { 0 };
+
+            let damage_to_first = second_action.melee_damage + second_action.ranged_damage + first_retaliation;
+            let damage_to_second = first_action.melee_damage + first_action.ranged_damage + second_retaliation;
+
+            first = first.apply_damage_and_heal(damage_to_first, first_action.heal);
+            second = second.apply_damage_and_heal(damage_to_second, second_action.heal);
+
+            if second.hits() == 0 {
+                first_ticks_to_kill = Some(tick);
+            }
+
+            if first.hits() == 0 {
+                second_ticks_to_kill = Some(tick);
+            }
+
+            if first.hits() == 0 || second.hits() == 0 {
+                break;
+            }
+        }
+    }
+
+    let winner = match (first.hits() > 0, second.hits() > 0) {
+        (true, false) => DuelWinner::First,
+        (false, true) => DuelWinner::Second,
+        (false, false) => DuelWinner::BothDestroyed,
+        (true, true) => DuelWinner::Neither,
+    };
+
+    DuelOutcome {
+        winner,
+        first_ticks_to_kill,
+        second_ticks_to_kill,
+        first_remaining: first,
+        second_remaining: second,
+    }
+}
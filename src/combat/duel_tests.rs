@@ -0,0 +1,110 @@
+use super::duel::{DuelConfig, DuelHealPolicy, DuelRange, DuelWinner, resolve_duel};
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn identical_melee_creeps_destroy_each_other() {
+    // Attack parts at the back, so they survive until the end
+    let creep = generate_bodyspec_from_string("2M 2A").unwrap();
+    let outcome = resolve_duel(&creep, &creep, &DuelConfig::default());
+
+    // 60 damage from each attack, plus 60 from retaliation
+    assert_eq!(DuelWinner::BothDestroyed, outcome.winner);
+    assert_eq!(Some(4), outcome.first_ticks_to_kill);
+    assert_eq!(Some(4), outcome.second_ticks_to_kill);
+    assert_eq!(0, outcome.first_hits());
+    assert_eq!(0, outcome.second_hits());
+}
+
+#[test]
+fn damage_destroys_parts_front_to_back() {
+    // Attack parts at the front are destroyed first, so the creeps stop fighting
+    let creep = generate_bodyspec_from_string("2A 2M").unwrap();
+    let outcome = resolve_duel(&creep, &creep, &DuelConfig::default());
+    assert_eq!(DuelWinner::Neither, outcome.winner);
+    assert_eq!(0, outcome.first_remaining.attack_damage());
+    assert_eq!(0, outcome.second_remaining.attack_damage());
+}
+
+#[test]
+fn retaliation_only_happens_in_melee_range() {
+    let ranged = generate_bodyspec_from_string("A R 2M").unwrap();
+    let melee = generate_bodyspec_from_string("5A M").unwrap();
+
+    let config = DuelConfig::new(DuelRange::Ranged, DuelHealPolicy::AttackFirst, DuelHealPolicy::AttackFirst, 1500);
+    let outcome = resolve_duel(&ranged, &melee, &config);
+    assert_eq!(DuelWinner::First, outcome.winner);
+    assert_eq!(Some(60), outcome.first_ticks_to_kill);
+    assert_eq!(400, outcome.first_hits());
+
+    let config = DuelConfig { range: DuelRange::Melee, ..config };
+    let outcome = resolve_duel(&ranged, &melee, &config);
+    assert_eq!(DuelWinner::Second, outcome.winner);
+}
+
+#[test]
+fn heal_policy_decides_between_attacking_and_healing() {
+    let first = generate_bodyspec_from_string("4M 2A 2H").unwrap();
+    let second = generate_bodyspec_from_string("3M 3R").unwrap();
+
+    // Attacking every tick wins the fight, with the ranged creep losing Ranged Attack parts from
+    // the 8th tick onwards
+    let config = DuelConfig::new(DuelRange::Melee, DuelHealPolicy::AttackFirst, DuelHealPolicy::AttackFirst, 100);
+    let outcome = resolve_duel(&first, &second, &config);
+    assert_eq!(DuelWinner::First, outcome.winner);
+    assert_eq!(Some(10), outcome.first_ticks_to_kill);
+    assert_eq!(800 - (7 * 30 + 20 + 20 + 10), outcome.first_hits());
+
+    // Healing every tick after the first can't keep up with the damage, and never attacks again
+    let config = DuelConfig { first_policy: DuelHealPolicy::HealWhenDamaged, ..config };
+    let outcome = resolve_duel(&first, &second, &config);
+    assert_eq!(DuelWinner::Neither, outcome.winner);
+    assert_eq!(None, outcome.first_ticks_to_kill);
+    assert_eq!(800 - 30 - 99 * 6, outcome.first_hits());
+    assert_eq!(600 - 60, outcome.second_hits());
+}
+
+#[test]
+fn healing_can_outlast_damage() {
+    let healer = generate_bodyspec_from_string("10H 10M").unwrap();
+    let ranged = generate_bodyspec_from_string("3R 3M").unwrap();
+
+    let outcome = resolve_duel(&healer, &ranged, &DuelConfig::default());
+    assert_eq!(DuelWinner::Neither, outcome.winner);
+    assert_eq!(healer, outcome.first_remaining);
+    assert_eq!(ranged, outcome.second_remaining);
+}
+
+#[test]
+fn boosted_tough_parts_reduce_damage() {
+    let attacker = generate_bodyspec_from_string("10A 10M").unwrap();
+    let unboosted = generate_bodyspec_from_string("5T 5R 10M").unwrap();
+    let boosted = generate_bodyspec_from_string("5T[T3Tough] 5R 10M").unwrap();
+
+    let config = DuelConfig::new(DuelRange::Melee, DuelHealPolicy::AttackFirst, DuelHealPolicy::AttackFirst, 1500);
+    let unboosted_outcome = resolve_duel(&attacker, &unboosted, &config);
+    let boosted_outcome = resolve_duel(&attacker, &boosted, &config);
+
+    // The attacker loses an Attack part every other tick to the ranged attacks
+    assert_eq!(Some(8), unboosted_outcome.first_ticks_to_kill);
+    assert!(boosted_outcome.first_ticks_to_kill.unwrap() > unboosted_outcome.first_ticks_to_kill.unwrap());
+    assert!(boosted_outcome.first_hits() < unboosted_outcome.first_hits());
+}
+
+#[test]
+fn damaged_creeps_start_with_fewer_capabilities() {
+    // The damage destroys the Attack part at the front of the body
+    let damaged = generate_bodyspec_from_string("A 2M").unwrap().apply_damage(100);
+    let target = generate_bodyspec_from_string("2M").unwrap();
+
+    let config = DuelConfig { max_ticks: 10, ..DuelConfig::default() };
+    let outcome = resolve_duel(&damaged, &target, &config);
+    assert_eq!(DuelWinner::Neither, outcome.winner);
+    assert_eq!(200, outcome.second_hits());
+
+    // A dead creep loses without a fight
+    let dead = generate_bodyspec_from_string("2A 2M").unwrap().apply_damage(400);
+    let outcome = resolve_duel(&target, &dead, &config);
+    assert_eq!(DuelWinner::First, outcome.winner);
+    assert_eq!(None, outcome.first_ticks_to_kill);
+}
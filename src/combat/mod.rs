@@ -0,0 +1,51 @@
+//! Provides helpers for planning and evaluating creeps built for combat.
+
+use crate::body::BodySpec;
+
+pub mod duel;
+pub mod pareto;
+pub mod ranged;
+pub mod siege;
+pub mod squad;
+pub mod tower;
+
+/// Calculates how many ticks a creep survives taking the specified damage every tick, while
+/// healing itself and receiving the specified healing from other sources.
+///
+/// Returns the tick on which the creep dies, or None if the healing keeps up with the damage.
+pub(crate) fn ticks_to_kill_while_healing(bodyspec: &BodySpec, damage: u32, external_heal: u32) -> Option<u32> {
+    let mut bodyspec = bodyspec.clone();
+    let mut tick = 0;
+
+    while bodyspec.hits() > 0 {
+        let hits = bodyspec.hits();
+        let heal = bodyspec.heal_amount().saturating_add(external_heal);
+        bodyspec = bodyspec.apply_damage_and_heal(damage, heal);
+        tick += 1;
+
+        // Once the healing keeps up with the damage, it always will
+        if bodyspec.hits() >= hits {
+            return None;
+        }
+    }
+
+    Some(tick)
+}
+
+#[cfg(test)]
+mod duel_tests;
+
+#[cfg(test)]
+mod pareto_tests;
+
+#[cfg(test)]
+mod ranged_tests;
+
+#[cfg(test)]
+mod siege_tests;
+
+#[cfg(test)]
+mod squad_tests;
+
+#[cfg(test)]
+mod tower_tests;
@@ -0,0 +1,239 @@
+//! Enumerates the trade-offs between damage, healing, and durability for combat bodies.
+
+use screeps::Part;
+use screeps::constants::MAX_CREEP_SIZE;
+
+use crate::body::{BodySpec, PartSpec};
+use crate::body::body_calculations::{
+    BoostSelectionConfig,
+    BoostTierChoice,
+    parts_to_move_offroad_by_parts_count,
+    parts_to_move_onroad_by_parts_count,
+};
+use crate::body::body_ordering::{BodyOrderingGoal, order_body};
+use crate::body::body_planning::MovementTarget;
+use crate::boost::boost::BoostCategory;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// A combat body, along with the metrics it's compared on.
+///
+/// With the `serde` feature enabled, a CombatBodyProfile is represented as a map with the keys
+/// `bodyspec`, `attack_damage`, `ranged_attack_damage`, `heal_amount`, and `effective_hits`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CombatBodyProfile {
+    /// The body, ordered for [Combat](BodyOrderingGoal::Combat)
+    pub bodyspec: BodySpec,
+
+    /// As per [BodySpec::attack_damage]
+    pub attack_damage: u32,
+
+    /// As per [BodySpec::ranged_attack_damage]
+    pub ranged_attack_damage: u32,
+
+    /// As per [BodySpec::heal_amount]
+    pub heal_amount: u32,
+
+    /// As per [BodySpec::effective_hits]
+    pub effective_hits: u32,
+}
+
+impl CombatBodyProfile {
+    /// Measures the combat metrics of a body.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::pareto::CombatBodyProfile;
+    ///
+    /// let profile = CombatBodyProfile::new(generate_bodyspec_from_string("2A H 3M").unwrap());
+    /// assert_eq!(60, profile.attack_damage);
+    /// assert_eq!(0, profile.ranged_attack_damage);
+    /// assert_eq!(12, profile.heal_amount);
+    /// assert_eq!(600, profile.effective_hits);
+    /// ```
+    pub fn new(bodyspec: BodySpec) -> Self {
+        CombatBodyProfile {
+            attack_damage: bodyspec.attack_damage(),
+            ranged_attack_damage: bodyspec.ranged_attack_damage(),
+            heal_amount: bodyspec.heal_amount(),
+            effective_hits: bodyspec.effective_hits(),
+            bodyspec,
+        }
+    }
+
+    /// Returns the metrics, in the order: [attack_damage, ranged_attack_damage, heal_amount,
+    /// effective_hits]
+    pub const fn metrics(&self) -> [u32; 4] {
+        [
+            self.attack_damage,
+            self.ranged_attack_damage,
+            self.heal_amount,
+            self.effective_hits,
+        ]
+    }
+
+    /// Whether this profile is at least as good as the other on every metric, and strictly better
+    /// on at least one.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::pareto::CombatBodyProfile;
+    ///
+    /// let small = CombatBodyProfile::new(generate_bodyspec_from_string("A M").unwrap());
+    /// let large = CombatBodyProfile::new(generate_bodyspec_from_string("2A 2M").unwrap());
+    /// let healer = CombatBodyProfile::new(generate_bodyspec_from_string("H M").unwrap());
+    ///
+    /// assert!(large.dominates(&small));
+    /// assert!(!small.dominates(&large));
+    /// assert!(!large.dominates(&healer));
+    /// assert!(!small.dominates(&small));
+    /// ```
+    pub fn dominates(&self, other: &CombatBodyProfile) -> bool {
+        let metrics = self.metrics();
+        let other_metrics = other.metrics();
+        let no_worse = metrics.iter().zip(other_metrics.iter()).all(|(m, o)| m >= o);
+        no_worse && metrics != other_metrics
+    }
+}
+
+/// The single boost tier used for every part of an enumerated body.
+///
+/// Boosts don't cost spawn energy, so boosting a body with a higher tier is always at least as
+/// good; choices that allow a range of tiers use the highest one.
+const fn uniform_boost_tier(choice: BoostTierChoice) -> BoostTierChoice {
+    match choice {
+        BoostTierChoice::NoBoosts => BoostTierChoice::NoBoosts,
+        BoostTierChoice::T1Only | BoostTierChoice::UpToT1 => BoostTierChoice::T1Only,
+        BoostTierChoice::T2Only | BoostTierChoice::UpToT2 => BoostTierChoice::T2Only,
+        BoostTierChoice::T3Only | BoostTierChoice::UpToT3 => BoostTierChoice::T3Only,
+    }
+}
+
+/// Creates a part for a boost category, boosted with a uniform tier.
+const fn part_spec_for_tier(category: BoostCategory, tier: BoostTierChoice) -> PartSpec {
+    let part = category.get_associated_part();
+    let [t1_boost, t2_boost, t3_boost] = category.get_abstract_boosts();
+    match tier {
+        BoostTierChoice::T1Only => PartSpec::new_boosted_part(part, t1_boost),
+        BoostTierChoice::T2Only => PartSpec::new_boosted_part(part, t2_boost),
+        BoostTierChoice::T3Only => PartSpec::new_boosted_part(part, t3_boost),
+        _ => PartSpec::new_unboosted_part(part),
+    }
+}
+
+/// Enumerates the Pareto-optimal combat bodies that can be spawned with the specified energy.
+///
+/// Bodies are made of Attack, Ranged Attack, Heal and Tough parts, along with the Move parts
+/// needed for the movement target, and are compared on melee damage, ranged damage, healing, and
+/// effective hits. A body is on the frontier if no other body is at least as good on all four and
+/// strictly better on one, so each returned body is a distinct trade-off between them.
+///
+/// Every part is boosted with the same tier. Boosts don't cost spawn energy, so choices that allow
+/// a range of tiers produce the same frontier as the highest tier they allow, and partial boosts
+/// aren't considered.
+///
+/// The frontier is ordered by the number of Attack parts, then Ranged Attack parts, then Heal
+/// parts, and is empty if no body fits the energy budget.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_calculations::BoostTierChoice;
+/// use screeps_body_utils::body::body_planning::MovementTarget;
+/// use screeps_body_utils::combat::pareto::combat_pareto_frontier;
+///
+/// let frontier = combat_pareto_frontier(1300, BoostTierChoice::NoBoosts, MovementTarget::OffRoad);
+/// assert!(!frontier.is_empty());
+///
+/// // No body on the frontier is better than another in every way
+/// for profile in &frontier {
+///     assert!(profile.bodyspec.energy_to_spawn() <= 1300);
+///     assert!(frontier.iter().all(|other| !other.dominates(profile)));
+/// }
+///
+/// // The strongest melee body trades away everything else for Attack parts
+/// let strongest = frontier.iter().max_by_key(|p| p.attack_damage).unwrap();
+/// assert_eq!(300, strongest.attack_damage);
+/// ```
+pub fn combat_pareto_frontier(energy_budget: u32, boost_tier_choice: BoostTierChoice, movement: MovementTarget) -> Vec<CombatBodyProfile> {
+    let max_parts = MAX_CREEP_SIZE as usize;
+    let tier = uniform_boost_tier(boost_tier_choice);
+    let move_config = BoostSelectionConfig::new(tier, false);
+
+    // The Move parts needed for each number of non-Move parts
+    let moves_needed: Vec<usize> = (0..=max_parts as u32)
+        .map(|num_non_move_parts| {
+            let summary = match movement {
+                MovementTarget::Stationary => return 0,
+                MovementTarget::OnRoad => parts_to_move_onroad_by_parts_count(num_non_move_parts, &move_config),
+                MovementTarget::OffRoad => parts_to_move_offroad_by_parts_count(num_non_move_parts, &move_config),
+            };
+            summary.map_or(usize::MAX, |s| s.num_parts())
+        })
+        .collect();
+
+    let attack_part = part_spec_for_tier(BoostCategory::Attack, tier);
+    let ranged_attack_part = part_spec_for_tier(BoostCategory::RangedAttack, tier);
+    let heal_part = part_spec_for_tier(BoostCategory::Heal, tier);
+    let tough_part = part_spec_for_tier(BoostCategory::Tough, tier);
+    let move_part = part_spec_for_tier(BoostCategory::Move, tier);
+
+    // Every part adds to effective hits, and Tough parts are the cheapest, so for each combination
+    // of Attack, Ranged Attack and Heal parts, the best body fills the rest with Tough parts
+    let index = |a: usize, r: usize, h: usize| (a * (max_parts + 1) + r) * (max_parts + 1) + h;
+    let mut candidates: Vec<Option<CombatBodyProfile>> = vec![None; (max_parts + 1).pow(3)];
+    for a in 0..=max_parts {
+        for r in 0..=(max_parts - a) {
+            for h in 0..=(max_parts - a - r) {
+                let base_parts = a + r + h;
+                let base_cost = a as u32 * Part::Attack.cost() + r as u32 * Part::RangedAttack.cost() + h as u32 * Part::Heal.cost();
+
+                let fits = |t: usize| {
+                    let num_non_move_parts = base_parts + t;
+                    let num_move_parts = moves_needed[num_non_move_parts];
+                    let cost = base_cost + t as u32 * Part::Tough.cost() + num_move_parts.saturating_mul(Part::Move.cost() as usize) as u32;
+                    num_non_move_parts > 0 && num_non_move_parts.saturating_add(num_move_parts) <= max_parts && cost <= energy_budget
+                };
+
+                let Some(t) = (0..=(max_parts - base_parts)).rev().find(|t| fits(*t)) else {
+                    continue;
+                };
+
+                let num_move_parts = moves_needed[base_parts + t];
+                let mut parts = Vec::with_capacity(base_parts + t + num_move_parts);
+                parts.extend(std::iter::repeat_n(tough_part, t));
+                parts.extend(std::iter::repeat_n(attack_part, a));
+                parts.extend(std::iter::repeat_n(ranged_attack_part, r));
+                parts.extend(std::iter::repeat_n(heal_part, h));
+                parts.extend(std::iter::repeat_n(move_part, num_move_parts));
+                let bodyspec = order_body(&parts, BodyOrderingGoal::Combat);
+
+                candidates[index(a, r, h)] = Some(CombatBodyProfile::new(bodyspec));
+            }
+        }
+    }
+
+    // Swapping an Attack, Ranged Attack or Heal part for a Tough part never lowers effective hits
+    // or adds cost, so if any body dominates a candidate, then so does one with a single extra
+    // Attack, Ranged Attack or Heal part. Only those neighbours need to be checked.
+    let mut frontier = Vec::new();
+    for a in 0..=max_parts {
+        for r in 0..=(max_parts - a) {
+            for h in 0..=(max_parts - a - r) {
+                let Some(candidate) = &candidates[index(a, r, h)] else {
+                    continue;
+                };
+
+                let mut neighbours = [(a + 1, r, h), (a, r + 1, h), (a, r, h + 1)].into_iter()
+                    .filter(|(a, r, h)| a + r + h <= max_parts)
+                    .filter_map(|(a, r, h)| candidates[index(a, r, h)].as_ref());
+
+                if !neighbours.any(|neighbour| neighbour.dominates(candidate)) {
+                    frontier.push(candidate.clone());
+                }
+            }
+        }
+    }
+
+    frontier
+}
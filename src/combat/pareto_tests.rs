@@ -0,0 +1,126 @@
+use screeps::Part;
+
+use super::pareto::{CombatBodyProfile, combat_pareto_frontier};
+use crate::body::body_calculations::BoostTierChoice;
+use crate::body::body_ordering::{BodyOrderingGoal, order_body};
+use crate::body::body_planning::MovementTarget;
+use crate::body::body_specification::generate_bodyspec_from_string;
+use crate::body::{BodySpec, PartSpec};
+use crate::boost::AbstractBoost;
+
+
+fn count_parts(profile: &CombatBodyProfile, part: Part) -> usize {
+    profile.bodyspec.part_specs().iter().filter(|p| p.part == part).count()
+}
+
+/// Finds the frontier by comparing every possible unboosted, off-road body against every other.
+fn brute_force_frontier(energy_budget: u32) -> Vec<[u32; 4]> {
+    let mut profiles = Vec::new();
+    for a in 0..=25 {
+        for r in 0..=(25 - a) {
+            for h in 0..=(25 - a - r) {
+                for t in 0..=(25 - a - r - h) {
+                    let n = a + r + h + t;
+                    let cost = a * 80 + r * 150 + h * 250 + t * 10 + n * 50;
+                    if n == 0 || cost > energy_budget {
+                        continue;
+                    }
+
+                    let mut parts = vec![PartSpec::new_unboosted_part(Part::Tough); t as usize];
+                    parts.extend(vec![PartSpec::new_unboosted_part(Part::Attack); a as usize]);
+                    parts.extend(vec![PartSpec::new_unboosted_part(Part::RangedAttack); r as usize]);
+                    parts.extend(vec![PartSpec::new_unboosted_part(Part::Heal); h as usize]);
+                    parts.extend(vec![PartSpec::new_unboosted_part(Part::Move); n as usize]);
+                    profiles.push(CombatBodyProfile::new(BodySpec::new(&parts)));
+                }
+            }
+        }
+    }
+
+    let mut frontier: Vec<[u32; 4]> = profiles.iter()
+        .filter(|p| !profiles.iter().any(|other| other.dominates(p)))
+        .map(|p| p.metrics())
+        .collect();
+    frontier.sort();
+    frontier.dedup();
+    frontier
+}
+
+#[test]
+fn frontier_matches_brute_force() {
+    for energy_budget in [60, 300, 550, 800] {
+        let mut frontier: Vec<[u32; 4]> = combat_pareto_frontier(energy_budget, BoostTierChoice::NoBoosts, MovementTarget::OffRoad)
+            .iter()
+            .map(|p| p.metrics())
+            .collect();
+        frontier.sort();
+        assert_eq!(brute_force_frontier(energy_budget), frontier, "Energy budget: {}", energy_budget);
+    }
+}
+
+#[test]
+fn frontier_bodies_fit_constraints() {
+    let movements = [MovementTarget::Stationary, MovementTarget::OnRoad, MovementTarget::OffRoad];
+    for movement in movements {
+        let frontier = combat_pareto_frontier(5600, BoostTierChoice::NoBoosts, movement);
+        assert!(!frontier.is_empty());
+
+        for profile in &frontier {
+            let bodyspec = &profile.bodyspec;
+            assert!(bodyspec.energy_to_spawn() <= 5600, "Movement: {:?}", movement);
+            assert!(bodyspec.part_specs().len() <= 50, "Movement: {:?}", movement);
+            assert_eq!(order_body(bodyspec.part_specs(), BodyOrderingGoal::Combat), *bodyspec);
+            match movement {
+                MovementTarget::Stationary => assert_eq!(0, count_parts(profile, Part::Move)),
+                MovementTarget::OnRoad => assert_eq!(0, bodyspec.road_move_net_exhaustion()),
+                MovementTarget::OffRoad => assert_eq!(0, bodyspec.plains_move_net_exhaustion()),
+            }
+        }
+    }
+}
+
+#[test]
+fn frontier_includes_extremes() {
+    let frontier = combat_pareto_frontier(1300, BoostTierChoice::NoBoosts, MovementTarget::OffRoad);
+
+    // 10 Attack parts, with 10 Move parts to carry them
+    let max_attack = frontier.iter().max_by_key(|p| p.attack_damage).unwrap();
+    assert_eq!(generate_bodyspec_from_string("10A 10M").unwrap(), max_attack.bodyspec);
+
+    // 4 Heal parts, with enough energy left for a Tough part
+    let max_heal = frontier.iter().max_by_key(|p| p.heal_amount).unwrap();
+    let expected = generate_bodyspec_from_string("T 4H 5M").unwrap();
+    assert_eq!(order_body(expected.part_specs(), BodyOrderingGoal::Combat), max_heal.bodyspec);
+
+    // Tough parts are the cheapest way to add hits
+    let max_hits = frontier.iter().max_by_key(|p| p.effective_hits).unwrap();
+    assert_eq!(generate_bodyspec_from_string("21T 21M").unwrap().get_parts(), max_hits.bodyspec.get_parts());
+}
+
+#[test]
+fn frontier_uses_highest_allowed_boost_tier() {
+    let t3 = combat_pareto_frontier(2300, BoostTierChoice::T3Only, MovementTarget::OffRoad);
+    let up_to_t3 = combat_pareto_frontier(2300, BoostTierChoice::UpToT3, MovementTarget::OffRoad);
+    assert_eq!(t3, up_to_t3);
+
+    for profile in &t3 {
+        for p in profile.bodyspec.part_specs() {
+            assert_eq!(Some(3), p.boost.map(|b| b.tier()), "Part: {:?}", p);
+        }
+    }
+
+    // T3 Move parts carry 4 parts each off-road
+    let max_attack = t3.iter().max_by_key(|p| p.attack_damage).unwrap();
+    let attack = PartSpec::new_boosted_part(Part::Attack, AbstractBoost::T3Attack);
+    assert!(max_attack.bodyspec.part_specs().contains(&attack));
+    assert_eq!(0, count_parts(max_attack, Part::RangedAttack) + count_parts(max_attack, Part::Heal));
+    let num_move_parts = count_parts(max_attack, Part::Move);
+    let num_non_move_parts = max_attack.bodyspec.part_specs().len() - num_move_parts;
+    assert_eq!(num_non_move_parts.div_ceil(4), num_move_parts);
+}
+
+#[test]
+fn frontier_is_empty_without_enough_energy() {
+    assert!(combat_pareto_frontier(59, BoostTierChoice::NoBoosts, MovementTarget::OffRoad).is_empty());
+    assert!(!combat_pareto_frontier(10, BoostTierChoice::NoBoosts, MovementTarget::Stationary).is_empty());
+}
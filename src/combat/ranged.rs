@@ -0,0 +1,164 @@
+//! Chooses between ranged attacks and ranged mass attacks against groups of targets.
+
+use crate::body::BodySpec;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The furthest distance that ranged attacks and ranged mass attacks can reach.
+const RANGED_ACTION_RANGE: u32 = 3;
+
+/// The position of a target relative to the attacking creep, in tiles.
+///
+/// With the `serde` feature enabled, a RelativePosition is represented as a map with the keys `dx`
+/// and `dy`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativePosition {
+    /// The horizontal offset from the attacking creep
+    pub dx: i32,
+
+    /// The vertical offset from the attacking creep
+    pub dy: i32,
+}
+
+impl RelativePosition {
+    pub const fn new(dx: i32, dy: i32) -> Self {
+        RelativePosition {
+            dx,
+            dy,
+        }
+    }
+
+    /// Calculates the range to the target, as the number of moves it takes to reach it, counting
+    /// diagonal moves.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::combat::ranged::RelativePosition;
+    ///
+    /// assert_eq!(0, RelativePosition::new(0, 0).distance());
+    /// assert_eq!(1, RelativePosition::new(-1, 1).distance());
+    /// assert_eq!(3, RelativePosition::new(2, -3).distance());
+    /// ```
+    pub const fn distance(&self) -> u32 {
+        let dx = self.dx.unsigned_abs();
+        let dy = self.dy.unsigned_abs();
+        if dx > dy { dx } else { dy }
+    }
+}
+
+/// A ranged action a creep can take in a tick.
+///
+/// With the `serde` feature enabled, a RangedAction is represented as either the variant name for
+/// `RangedMassAttack`, or a map from the variant name to the target index for `RangedAttack` (i.e.
+/// `{"RangedAttack": 0}`).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RangedAction {
+    /// [Ranged attack](screeps::Creep::ranged_attack) the target at the specified index
+    RangedAttack(usize),
+
+    /// [Ranged mass attack](screeps::Creep::ranged_mass_attack) every target in range
+    RangedMassAttack,
+}
+
+/// The best ranged action against a group of targets, along with the total damage it deals.
+///
+/// With the `serde` feature enabled, a RangedActionChoice is represented as a map with the keys
+/// `action` and `damage`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RangedActionChoice {
+    /// The action to take
+    pub action: RangedAction,
+
+    /// The total damage the action deals across all of the targets
+    pub damage: u32,
+}
+
+/// Calculates the total damage a ranged mass attack deals to targets at the specified positions.
+///
+/// Each target takes damage based on its own range, as per
+/// [BodySpec::ranged_mass_attack_damage_at_distance_single_target]. Targets on the same tile as
+/// the creep, such as a rampart it's standing on, take the same damage as adjacent ones, matching
+/// the engine.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::ranged::{RelativePosition, ranged_mass_attack_damage};
+///
+/// let bodyspec = generate_bodyspec_from_string("10R 10M").unwrap();
+/// let targets = [
+///     RelativePosition::new(1, 0),
+///     RelativePosition::new(-2, 1),
+///     RelativePosition::new(3, 3),
+///     RelativePosition::new(0, 4),
+/// ];
+/// assert_eq!(100 + 40 + 10, ranged_mass_attack_damage(&bodyspec, &targets));
+/// ```
+pub fn ranged_mass_attack_damage(bodyspec: &BodySpec, targets: &[RelativePosition]) -> u32 {
+    targets.iter()
+        .map(|target| {
+            let distance = target.distance().max(1);
+            if distance > RANGED_ACTION_RANGE {
+                return 0;
+            }
+            bodyspec.ranged_mass_attack_damage_at_distance_single_target(distance as u8)
+        })
+        .sum()
+}
+
+/// Chooses between a ranged attack on a single target and a ranged mass attack, whichever deals
+/// the most total damage to targets at the specified positions.
+///
+/// A ranged attack deals full damage to any target in range, so the first target in range is
+/// chosen. If both actions deal the same damage, the ranged attack is preferred, since it can't
+/// hit anything that isn't a target.
+///
+/// Returns None if the creep can't deal any damage, either because it has no active Ranged Attack
+/// parts or because no target is in range.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::ranged::{RangedAction, RelativePosition, best_ranged_action};
+///
+/// let bodyspec = generate_bodyspec_from_string("10R 10M").unwrap();
+///
+/// // A single target is best hit with a ranged attack
+/// let targets = [RelativePosition::new(2, 2)];
+/// let choice = best_ranged_action(&bodyspec, &targets).unwrap();
+/// assert_eq!(RangedAction::RangedAttack(0), choice.action);
+/// assert_eq!(100, choice.damage);
+///
+/// // Several nearby targets are best hit with a ranged mass attack
+/// let targets = [RelativePosition::new(1, 1), RelativePosition::new(0, -2)];
+/// let choice = best_ranged_action(&bodyspec, &targets).unwrap();
+/// assert_eq!(RangedAction::RangedMassAttack, choice.action);
+/// assert_eq!(100 + 40, choice.damage);
+///
+/// // Nothing is in range
+/// assert_eq!(None, best_ranged_action(&bodyspec, &[RelativePosition::new(4, 0)]));
+/// ```
+pub fn best_ranged_action(bodyspec: &BodySpec, targets: &[RelativePosition]) -> Option<RangedActionChoice> {
+    let target_index = targets.iter().position(|target| target.distance() <= RANGED_ACTION_RANGE)?;
+    let ranged_attack_damage = bodyspec.ranged_attack_damage();
+    if ranged_attack_damage == 0 {
+        return None;
+    }
+
+    let mass_attack_damage = ranged_mass_attack_damage(bodyspec, targets);
+
+    let choice = if mass_attack_damage > ranged_attack_damage {
+        RangedActionChoice {
+            action: RangedAction::RangedMassAttack,
+            damage: mass_attack_damage,
+        }
+    } else {
+        RangedActionChoice {
+            action: RangedAction::RangedAttack(target_index),
+            damage: ranged_attack_damage,
+        }
+    };
+
+    Some(choice)
+}
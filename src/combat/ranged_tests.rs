@@ -0,0 +1,64 @@
+use super::ranged::*;
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn mass_attack_damage_falls_off_with_range() {
+    let attacker = generate_bodyspec_from_string("R M").unwrap();
+    let test_data = [
+        ((0, 0), 10),
+        ((1, -1), 10),
+        ((2, 0), 4),
+        ((-1, -2), 4),
+        ((3, 1), 1),
+        ((-3, -3), 1),
+        ((4, 0), 0),
+        ((0, -50), 0),
+    ];
+
+    for ((dx, dy), damage) in test_data {
+        let target = RelativePosition::new(dx, dy);
+        assert_eq!(damage, ranged_mass_attack_damage(&attacker, &[target]), "Target: {:?}", target);
+    }
+}
+
+#[test]
+fn mass_attack_damage_uses_boosts() {
+    let attacker = generate_bodyspec_from_string("5R[T3RangedAttack] 5M").unwrap();
+    let targets = [RelativePosition::new(1, 0), RelativePosition::new(0, 2), RelativePosition::new(3, 0)];
+    assert_eq!(4 * 5 * (10 + 4 + 1), ranged_mass_attack_damage(&attacker, &targets));
+}
+
+#[test]
+fn best_ranged_action_compares_total_damage() {
+    let attacker = generate_bodyspec_from_string("10R 10M").unwrap();
+    let test_data = [
+        // A single adjacent target takes the same damage either way
+        (vec![(1, 0)], RangedAction::RangedAttack(0), 100),
+        // Two targets at range 2 take less than a single ranged attack
+        (vec![(2, 0), (0, 2)], RangedAction::RangedAttack(0), 100),
+        // But three don't
+        (vec![(2, 0), (0, 2), (-2, -2)], RangedAction::RangedMassAttack, 120),
+        (vec![(1, 0), (0, 1)], RangedAction::RangedMassAttack, 200),
+        // Targets out of range are skipped
+        (vec![(5, 0), (3, 3)], RangedAction::RangedAttack(1), 100),
+    ];
+
+    for (positions, action, damage) in test_data {
+        let targets: Vec<_> = positions.iter().map(|(dx, dy)| RelativePosition::new(*dx, *dy)).collect();
+        let choice = best_ranged_action(&attacker, &targets).unwrap();
+        assert_eq!(RangedActionChoice { action, damage }, choice, "Targets: {:?}", positions);
+    }
+}
+
+#[test]
+fn best_ranged_action_needs_damage() {
+    let targets = [RelativePosition::new(1, 0)];
+    assert_eq!(None, best_ranged_action(&generate_bodyspec_from_string("10A 10M").unwrap(), &targets));
+    assert_eq!(None, best_ranged_action(&generate_bodyspec_from_string("10R 10M").unwrap(), &[]));
+    assert_eq!(None, best_ranged_action(&generate_bodyspec_from_string("10R 10M").unwrap(), &[RelativePosition::new(-4, 4)]));
+
+    // Destroyed Ranged Attack parts don't deal damage
+    let damaged = generate_bodyspec_from_string("10R 10M").unwrap().apply_damage_and_heal(1000, 0);
+    assert_eq!(None, best_ranged_action(&damaged, &targets));
+}
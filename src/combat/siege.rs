@@ -0,0 +1,99 @@
+//! Estimates how long it takes a group of creeps to breach a structure, such as a rampart or a
+//! wall, against the defenders' repairs.
+
+use crate::body::BodySpec;
+use super::tower::tower_repair_amount;
+
+/// Calculates the damage a single creep can deal to a structure each tick.
+///
+/// The engine doesn't allow a creep to both attack and dismantle in the same tick, so the creep
+/// uses whichever of its Attack or Work parts deal more damage, along with its Ranged Attack parts.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::siege::structure_damage;
+///
+/// assert_eq!(500, structure_damage(&generate_bodyspec_from_string("10W 10M").unwrap()));
+/// assert_eq!(300, structure_damage(&generate_bodyspec_from_string("10A 10M").unwrap()));
+///
+/// // 5 Attack parts deal more than 2 Work parts, and the Ranged Attack part deals damage as well
+/// assert_eq!(150 + 10, structure_damage(&generate_bodyspec_from_string("2W 5A R 8M").unwrap()));
+/// ```
+pub fn structure_damage(bodyspec: &BodySpec) -> u32 {
+    bodyspec.attack_damage().max(bodyspec.dismantle_damage()) + bodyspec.ranged_attack_damage()
+}
+
+/// Calculates the combined damage a group of creeps can deal to a structure each tick, as per
+/// [structure_damage].
+pub fn siege_damage(attackers: &[BodySpec]) -> u32 {
+    attackers.iter().map(structure_damage).sum()
+}
+
+/// Calculates the combined hits that repairers and towers at each of the specified ranges can
+/// restore to a structure each tick.
+///
+/// Each repairer restores its [repair_amount](BodySpec::repair_amount), rounded down as per the
+/// engine, and each tower restores its [tower_repair_amount].
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::siege::defender_repair_amount;
+///
+/// let repairer = generate_bodyspec_from_string("10W 10C 10M").unwrap();
+/// assert_eq!(1000, defender_repair_amount(&[repairer.clone()], &[]));
+/// assert_eq!(1000 + 800 + 200, defender_repair_amount(&[repairer], &[5, 20]));
+/// ```
+pub fn defender_repair_amount(repairers: &[BodySpec], tower_ranges: &[u8]) -> u32 {
+    let repairer_amount: u32 = repairers.iter().map(|r| r.repair_amount() as u32).sum();
+    let tower_amount: u32 = tower_ranges.iter().map(|range| tower_repair_amount(*range)).sum();
+    repairer_amount + tower_amount
+}
+
+/// Calculates how many ticks a group of creeps needs to breach a structure with the specified
+/// hits, while the defenders repair it by the specified amount each tick.
+///
+/// The attackers deal their [siege_damage] every tick, and the repairs are applied in the same
+/// tick, so the structure loses the difference each tick. The defenders' repair amount can be
+/// calculated with [defender_repair_amount].
+///
+/// The engine destroys a structure as soon as an attack takes its hits to 0, so repairs don't save
+/// it on the final tick. This means a structure the attackers can destroy in a single tick is
+/// always breached, even if the repairs would otherwise keep up with the damage.
+///
+/// Returns the tick on which the structure is destroyed, or None if the repairs keep up with the
+/// damage. A structure with no hits left is already breached, taking 0 ticks.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::siege::{defender_repair_amount, ticks_to_breach};
+///
+/// let dismantler = generate_bodyspec_from_string("25W[T3Dismantle] 25M").unwrap();
+/// let repairer = generate_bodyspec_from_string("10W 10C 10M").unwrap();
+///
+/// // 5000 damage per tick against 1000 hits of repairs per tick
+/// let repairs = defender_repair_amount(&[repairer], &[]);
+/// assert_eq!(Some(250), ticks_to_breach(&[dismantler.clone()], 1_000_000, repairs));
+///
+/// // Six towers repairing at close range make up for most of the damage
+/// let repairs = defender_repair_amount(&[], &[5; 6]);
+/// assert_eq!(Some(4976), ticks_to_breach(&[dismantler.clone()], 1_000_000, repairs));
+///
+/// // But can't keep up with a second dismantler
+/// assert_eq!(Some(192), ticks_to_breach(&[dismantler.clone(), dismantler], 1_000_000, repairs));
+/// ```
+pub fn ticks_to_breach(attackers: &[BodySpec], structure_hits: u32, repair_per_tick: u32) -> Option<u32> {
+    if structure_hits == 0 {
+        return Some(0);
+    }
+
+    let damage = siege_damage(attackers);
+    if damage >= structure_hits {
+        return Some(1);
+    }
+    if damage <= repair_per_tick {
+        return None;
+    }
+
+    // The repairs only help on the ticks before the final attack
+    Some(1 + (structure_hits - damage).div_ceil(damage - repair_per_tick))
+}
@@ -0,0 +1,81 @@
+use super::siege::*;
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn structure_damage_uses_the_strongest_melee_action() {
+    let test_data = [
+        ("10W 10M", 500),
+        ("10W[T3Dismantle] 10M", 2000),
+        ("10A 10M", 300),
+        ("10A[T3Attack] 10M", 1200),
+        ("10R 10M", 100),
+        ("5W 5A 5R 15M", 250 + 50),
+        ("5W 10A 5R 20M", 300 + 50),
+        ("10C 10M", 0),
+    ];
+
+    for (body_string, damage) in test_data {
+        assert_eq!(damage, structure_damage(&generate_bodyspec_from_string(body_string).unwrap()), "Body: {}", body_string);
+    }
+
+    let attackers = [generate_bodyspec_from_string("10W 10M").unwrap(), generate_bodyspec_from_string("10A 10M").unwrap(), generate_bodyspec_from_string("10R 10M").unwrap()];
+    assert_eq!(500 + 300 + 100, siege_damage(&attackers));
+    assert_eq!(0, siege_damage(&[]));
+}
+
+#[test]
+fn defender_repairs_add_up() {
+    // Each T1 boosted Work part repairs 150 hits
+    let repairer = generate_bodyspec_from_string("W[T1BuildRepair] C M").unwrap();
+    assert_eq!(300, defender_repair_amount(&[repairer.clone(), repairer], &[]));
+    assert_eq!(150 + 500, defender_repair_amount(&[generate_bodyspec_from_string("W[T1BuildRepair] C M").unwrap(), generate_bodyspec_from_string("5W 5C 5M").unwrap()], &[]));
+
+    assert_eq!(800 + 600 + 200, defender_repair_amount(&[], &[1, 10, 25]));
+    assert_eq!(0, defender_repair_amount(&[], &[]));
+}
+
+#[test]
+fn ticks_to_breach_accounts_for_repairs() {
+    let attackers = [generate_bodyspec_from_string("10W 10M").unwrap(), generate_bodyspec_from_string("10A 10M").unwrap()];
+
+    assert_eq!(Some(0), ticks_to_breach(&attackers, 0, 0));
+    assert_eq!(Some(1), ticks_to_breach(&attackers, 800, 0));
+    assert_eq!(Some(2), ticks_to_breach(&attackers, 801, 0));
+    assert_eq!(Some(9), ticks_to_breach(&attackers, 3000, 500));
+    assert_eq!(Some(2201), ticks_to_breach(&attackers, 3000, 799));
+
+    assert_eq!(None, ticks_to_breach(&attackers, 3000, 800));
+    assert_eq!(None, ticks_to_breach(&attackers, 3000, 1000));
+    assert_eq!(None, ticks_to_breach(&[], 3000, 0));
+}
+
+#[test]
+fn ticks_to_breach_ignores_repairs_on_the_final_tick() {
+    let attackers = [generate_bodyspec_from_string("10W 10M").unwrap(), generate_bodyspec_from_string("10A 10M").unwrap()];
+
+    // After 8 ticks of losing 300 hits, the last 800 hits are destroyed before the repairs land
+    assert_eq!(Some(9), ticks_to_breach(&attackers, 3200, 500));
+    assert_eq!(Some(10), ticks_to_breach(&attackers, 3201, 500));
+
+    // Destroying the structure in one tick can't be out-repaired
+    assert_eq!(Some(1), ticks_to_breach(&attackers, 800, 800));
+    assert_eq!(Some(1), ticks_to_breach(&attackers, 800, 1000));
+    assert_eq!(Some(1), ticks_to_breach(&attackers, 1, 1000));
+    assert_eq!(None, ticks_to_breach(&attackers, 801, 1000));
+}
+
+#[test]
+fn ticks_to_breach_uses_engine_tower_repairs() {
+    // A tower at range 16 repairs exactly 360 hits, which is easy to get 1 lower with floating
+    // point rounding
+    let repairs = defender_repair_amount(&[], &[16]);
+    assert_eq!(360, repairs);
+
+    let attackers = [generate_bodyspec_from_string("12A 12M").unwrap()];
+    assert_eq!(None, ticks_to_breach(&attackers, 1000, repairs));
+
+    let attackers = [generate_bodyspec_from_string("10W 10M").unwrap()];
+    assert_eq!(Some(7), ticks_to_breach(&attackers, 1340, repairs));
+    assert_eq!(Some(8), ticks_to_breach(&attackers, 1341, repairs));
+}
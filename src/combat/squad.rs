@@ -0,0 +1,208 @@
+//! Analyzes groups of creeps that fight and move together, such as duos and quads.
+
+use crate::body::{BodySpec, TileType};
+use super::ticks_to_kill_while_healing;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// How far the other members of a squad are from the member they're healing.
+///
+/// With the `serde` feature enabled, a SquadHealRange is represented as the variant name.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SquadHealRange {
+    /// Adjacent, so the other members heal with their full [heal_amount](BodySpec::heal_amount)
+    Adjacent,
+
+    /// Further away, so the other members heal with their
+    /// [ranged_heal_amount](BodySpec::ranged_heal_amount)
+    Ranged,
+}
+
+/// A group of creeps that fight and move together.
+///
+/// With the `serde` feature enabled, a Squad is represented as a sequence of its members, as per
+/// [BodySpec].
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::squad::Squad;
+///
+/// let attacker = generate_bodyspec_from_string("5T 20A 25M").unwrap();
+/// let healer = generate_bodyspec_from_string("25M 25H").unwrap();
+/// let duo = Squad::new(&[attacker, healer]);
+///
+/// assert_eq!(600, duo.attack_damage());
+/// assert_eq!(300, duo.heal_amount());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Squad {
+    members: Vec<BodySpec>,
+}
+
+impl Squad {
+    pub fn new(members: &[BodySpec]) -> Self {
+        Squad {
+            members: members.to_vec(),
+        }
+    }
+
+    /// Returns the bodies of the squad's members.
+    pub fn members(&self) -> &[BodySpec] {
+        &self.members
+    }
+
+    /// Calculates the combined hits that the squad's members can restore to adjacent targets.
+    pub fn heal_amount(&self) -> u32 {
+        self.members.iter().map(|m| m.heal_amount()).sum()
+    }
+
+    /// Calculates the combined hits that the squad's members can restore to targets at range.
+    pub fn ranged_heal_amount(&self) -> u32 {
+        self.members.iter().map(|m| m.ranged_heal_amount()).sum()
+    }
+
+    /// Calculates the combined melee damage that the squad's members can deal.
+    pub fn attack_damage(&self) -> u32 {
+        self.members.iter().map(|m| m.attack_damage()).sum()
+    }
+
+    /// Calculates the combined ranged attack damage that the squad's members can deal.
+    pub fn ranged_attack_damage(&self) -> u32 {
+        self.members.iter().map(|m| m.ranged_attack_damage()).sum()
+    }
+
+    /// Calculates the combined damage that the squad's members can deal to a structure by
+    /// dismantling it.
+    pub fn dismantle_damage(&self) -> u32 {
+        self.members.iter().map(|m| m.dismantle_damage()).sum()
+    }
+
+    /// Calculates the combined effective damage that the squad's members can sustain.
+    pub fn effective_hits(&self) -> u32 {
+        self.members.iter().map(|m| m.effective_hits()).sum()
+    }
+
+    /// Calculates how many ticks a member survives when every enemy focuses their fire on it,
+    /// while it heals itself and the rest of the squad heal it from the specified range.
+    ///
+    /// The rest of the squad is assumed to be at full health, putting all of their healing into
+    /// the focused member. Damage and healing are applied as per
+    /// [BodySpec::apply_damage_and_heal].
+    ///
+    /// Returns the tick on which the member dies, or None if the squad's healing keeps up with the
+    /// damage. Also returns None if there's no member at the specified index.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::squad::{Squad, SquadHealRange};
+    ///
+    /// let attacker = generate_bodyspec_from_string("5T 20A 25M").unwrap();
+    /// let healer = generate_bodyspec_from_string("25M 25H").unwrap();
+    /// let duo = Squad::new(&[attacker, healer]);
+    ///
+    /// // The healer can heal 300 hits per tick when adjacent
+    /// assert_eq!(None, duo.focus_fire_ticks_to_kill(0, 300, SquadHealRange::Adjacent));
+    ///
+    /// // But only 100 hits at range
+    /// assert!(duo.focus_fire_ticks_to_kill(0, 300, SquadHealRange::Ranged).is_some());
+    /// ```
+    pub fn focus_fire_ticks_to_kill(&self, index: usize, damage: u32, heal_range: SquadHealRange) -> Option<u32> {
+        let target = self.members.get(index)?;
+        let external_heal = self.members.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, m)| match heal_range {
+                SquadHealRange::Adjacent => m.heal_amount(),
+                SquadHealRange::Ranged => m.ranged_heal_amount(),
+            })
+            .sum();
+
+        ticks_to_kill_while_healing(target, damage, external_heal)
+    }
+
+    /// Finds the member that dies the soonest under focused fire, as per
+    /// [focus_fire_ticks_to_kill](Squad::focus_fire_ticks_to_kill).
+    ///
+    /// Returns the index of the member and the tick on which it dies, or None if every member can
+    /// be healed through the damage, or the squad is empty.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::squad::{Squad, SquadHealRange};
+    ///
+    /// let attacker = generate_bodyspec_from_string("20A 20M").unwrap();
+    /// let healer = generate_bodyspec_from_string("25M 25H").unwrap();
+    /// let duo = Squad::new(&[attacker, healer]);
+    ///
+    /// // The healer heals itself as much as it heals the attacker, but has more hits
+    /// assert_eq!(Some((0, 4)), duo.weakest_member(1500, SquadHealRange::Adjacent));
+    ///
+    /// // Neither member dies if the healing keeps up
+    /// assert_eq!(None, duo.weakest_member(300, SquadHealRange::Adjacent));
+    /// ```
+    pub fn weakest_member(&self, damage: u32, heal_range: SquadHealRange) -> Option<(usize, u32)> {
+        (0..self.members.len())
+            .filter_map(|i| self.focus_fire_ticks_to_kill(i, damage, heal_range).map(|ticks| (i, ticks)))
+            .min_by_key(|(_, ticks)| *ticks)
+    }
+
+    /// Calculates the highest net fatigue that any member generates by moving onto the specified
+    /// kind of tile, with empty stores.
+    ///
+    /// The squad can only move as fast as this slowest member.
+    pub fn max_net_exhaustion(&self, tile: TileType) -> u32 {
+        self.members.iter()
+            .map(|m| m.move_net_exhaustion_with_store(tile, 0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether every member can move onto the specified kind of tile every tick, so the squad can
+    /// keep its formation.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::TileType;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::squad::Squad;
+    ///
+    /// let attacker = generate_bodyspec_from_string("5T 20A 25M").unwrap();
+    /// let healer = generate_bodyspec_from_string("25M 25H").unwrap();
+    /// let duo = Squad::new(&[attacker, healer]);
+    ///
+    /// assert!(duo.can_move_together(TileType::Plain));
+    /// assert!(!duo.can_move_together(TileType::Swamp));
+    /// ```
+    pub fn can_move_together(&self, tile: TileType) -> bool {
+        self.max_net_exhaustion(tile) == 0
+    }
+
+    /// Calculates the number of ticks the squad needs to move along a path together, which is the
+    /// time taken by its slowest member.
+    ///
+    /// Returns None if any member can't move, or the squad is empty.
+    ///
+    /// ```rust
+    /// use screeps_body_utils::body::TileType;
+    /// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+    /// use screeps_body_utils::combat::squad::Squad;
+    ///
+    /// let fast = generate_bodyspec_from_string("5A 5M").unwrap();
+    /// let slow = generate_bodyspec_from_string("4H M").unwrap();
+    /// let duo = Squad::new(&[fast.clone(), slow]);
+    ///
+    /// let path = [TileType::Plain; 3];
+    /// assert_eq!(fast.simulate_movement(&path).map(|s| s.total_ticks), Some(3));
+    /// assert_eq!(Some(9), duo.ticks_to_traverse(&path));
+    /// ```
+    pub fn ticks_to_traverse(&self, path: &[TileType]) -> Option<u32> {
+        let mut max_ticks = None;
+        for member in &self.members {
+            let ticks = member.simulate_movement(path)?.total_ticks;
+            max_ticks = Some(max_ticks.map_or(ticks, |max: u32| max.max(ticks)));
+        }
+        max_ticks
+    }
+}
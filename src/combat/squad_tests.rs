@@ -0,0 +1,93 @@
+use super::squad::*;
+use crate::body::TileType;
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn squad_pools_member_output() {
+    let squad = Squad::new(&[
+        generate_bodyspec_from_string("5T 10A 15M").unwrap(),
+        generate_bodyspec_from_string("10R 10M").unwrap(),
+        generate_bodyspec_from_string("5W 5M").unwrap(),
+        generate_bodyspec_from_string("10H[T3Heal] 10M").unwrap(),
+    ]);
+
+    assert_eq!(4, squad.members().len());
+    assert_eq!(300, squad.attack_damage());
+    assert_eq!(100, squad.ranged_attack_damage());
+    assert_eq!(250, squad.dismantle_damage());
+    assert_eq!(480, squad.heal_amount());
+    assert_eq!(160, squad.ranged_heal_amount());
+
+    let member_hits: u32 = squad.members().iter().map(|m| m.effective_hits()).sum();
+    assert_eq!(member_hits, squad.effective_hits());
+}
+
+#[test]
+fn empty_squad_has_no_output() {
+    let squad = Squad::new(&[]);
+
+    assert_eq!(0, squad.attack_damage());
+    assert_eq!(0, squad.heal_amount());
+    assert_eq!(0, squad.effective_hits());
+    assert_eq!(None, squad.weakest_member(1000, SquadHealRange::Adjacent));
+    assert_eq!(0, squad.max_net_exhaustion(TileType::Swamp));
+    assert!(squad.can_move_together(TileType::Swamp));
+    assert_eq!(None, squad.ticks_to_traverse(&[TileType::Plain]));
+}
+
+#[test]
+fn focus_fire_is_offset_by_squad_healing() {
+    let attacker = generate_bodyspec_from_string("10A 10M").unwrap();
+    let healer = generate_bodyspec_from_string("10M 10H").unwrap();
+    let duo = Squad::new(&[attacker.clone(), healer]);
+
+    // Alone, the attacker dies to 120 damage per tick
+    assert_eq!(Some(17), Squad::new(&[attacker]).focus_fire_ticks_to_kill(0, 120, SquadHealRange::Adjacent));
+
+    // An adjacent healer heals all of it, but only a third of it at range
+    assert_eq!(None, duo.focus_fire_ticks_to_kill(0, 120, SquadHealRange::Adjacent));
+    assert_eq!(Some(25), duo.focus_fire_ticks_to_kill(0, 120, SquadHealRange::Ranged));
+
+    assert_eq!(None, duo.focus_fire_ticks_to_kill(2, 120, SquadHealRange::Adjacent));
+}
+
+#[test]
+fn weakest_member_dies_first_under_focus_fire() {
+    let squad = Squad::new(&[
+        generate_bodyspec_from_string("2T[T3Tough] 10A 12M").unwrap(),
+        generate_bodyspec_from_string("10R 10M").unwrap(),
+        generate_bodyspec_from_string("10H 10M").unwrap(),
+    ]);
+
+    // Nobody heals the healer, and its Heal parts at the front are destroyed first
+    let (index, ticks) = squad.weakest_member(600, SquadHealRange::Adjacent).unwrap();
+    assert_eq!(2, index);
+    assert_eq!(squad.focus_fire_ticks_to_kill(2, 600, SquadHealRange::Adjacent), Some(ticks));
+
+    for i in 0..squad.members().len() {
+        let member_ticks = squad.focus_fire_ticks_to_kill(i, 600, SquadHealRange::Adjacent);
+        assert!(member_ticks.is_some_and(|t| t >= ticks), "Member: {}", i);
+    }
+}
+
+#[test]
+fn squad_moves_at_the_pace_of_its_slowest_member() {
+    let fast = generate_bodyspec_from_string("5A 5M").unwrap();
+    let slow = generate_bodyspec_from_string("10A 5M").unwrap();
+    let squad = Squad::new(&[fast.clone(), slow.clone()]);
+
+    assert_eq!(0, squad.max_net_exhaustion(TileType::Road));
+    assert_eq!(slow.move_net_exhaustion_with_store(TileType::Plain, 0), squad.max_net_exhaustion(TileType::Plain));
+    assert!(squad.can_move_together(TileType::Road));
+    assert!(!squad.can_move_together(TileType::Plain));
+    assert!(Squad::new(&[generate_bodyspec_from_string("5A 5M").unwrap()]).can_move_together(TileType::Plain));
+
+    let path = [TileType::Plain, TileType::Swamp, TileType::Road];
+    let slow_ticks = slow.simulate_movement(&path).unwrap().total_ticks;
+    assert!(fast.simulate_movement(&path).unwrap().total_ticks < slow_ticks);
+    assert_eq!(Some(slow_ticks), squad.ticks_to_traverse(&path));
+
+    let stuck = Squad::new(&[fast, generate_bodyspec_from_string("5A").unwrap()]);
+    assert_eq!(None, stuck.ticks_to_traverse(&path));
+}
@@ -0,0 +1,162 @@
+//! Models tower effectiveness over range, and whether creeps can survive tower fire.
+
+use screeps::constants::{
+    StructureType,
+    TOWER_FALLOFF,
+    TOWER_FALLOFF_RANGE,
+    TOWER_OPTIMAL_RANGE,
+    TOWER_POWER_ATTACK,
+    TOWER_POWER_HEAL,
+    TOWER_POWER_REPAIR,
+};
+
+use crate::body::BodySpec;
+use super::ticks_to_kill_while_healing;
+
+/// The most towers a room can have.
+pub const MAX_TOWERS: u32 = StructureType::Tower.controller_structures(8);
+
+/// Applies the tower range falloff to an amount of power.
+///
+/// Matches the [engine
+/// code](https://github.com/screeps/engine/blob/master/src/processor/intents/towers/attack.js):
+/// full power up to [TOWER_OPTIMAL_RANGE], dropping linearly to a `1 - TOWER_FALLOFF` fraction of
+/// it at [TOWER_FALLOFF_RANGE] and beyond, rounded down.
+///
+/// The falloff is evaluated in the same order as the engine, since floating point rounding would
+/// otherwise make some amounts come out 1 lower.
+const fn tower_power_at_range(power: u32, range: u8) -> u32 {
+    if range <= TOWER_OPTIMAL_RANGE {
+        return power;
+    }
+
+    let range = if range > TOWER_FALLOFF_RANGE { TOWER_FALLOFF_RANGE } else { range };
+    let power = power as f64;
+    let falloff = power * TOWER_FALLOFF * (range - TOWER_OPTIMAL_RANGE) as f64 / (TOWER_FALLOFF_RANGE - TOWER_OPTIMAL_RANGE) as f64;
+    (power - falloff) as u32
+}
+
+/// Calculates the damage a single tower deals to a target at the specified range.
+///
+/// ```rust
+/// use screeps::constants::TOWER_POWER_ATTACK;
+/// use screeps_body_utils::combat::tower::tower_attack_damage;
+///
+/// assert_eq!(TOWER_POWER_ATTACK, tower_attack_damage(5));
+/// assert_eq!(450, tower_attack_damage(10));
+/// assert_eq!(150, tower_attack_damage(20));
+/// assert_eq!(150, tower_attack_damage(40));
+/// ```
+pub const fn tower_attack_damage(range: u8) -> u32 {
+    tower_power_at_range(TOWER_POWER_ATTACK, range)
+}
+
+/// Calculates the hits a single tower heals on a target at the specified range.
+///
+/// ```rust
+/// use screeps::constants::TOWER_POWER_HEAL;
+/// use screeps_body_utils::combat::tower::tower_heal_amount;
+///
+/// assert_eq!(TOWER_POWER_HEAL, tower_heal_amount(1));
+/// assert_eq!(100, tower_heal_amount(20));
+/// ```
+pub const fn tower_heal_amount(range: u8) -> u32 {
+    tower_power_at_range(TOWER_POWER_HEAL, range)
+}
+
+/// Calculates the hits a single tower repairs on a target at the specified range.
+///
+/// ```rust
+/// use screeps::constants::TOWER_POWER_REPAIR;
+/// use screeps_body_utils::combat::tower::tower_repair_amount;
+///
+/// assert_eq!(TOWER_POWER_REPAIR, tower_repair_amount(0));
+/// assert_eq!(200, tower_repair_amount(20));
+/// ```
+pub const fn tower_repair_amount(range: u8) -> u32 {
+    tower_power_at_range(TOWER_POWER_REPAIR, range)
+}
+
+/// Calculates the combined damage dealt by towers at each of the specified ranges.
+///
+/// ```rust
+/// use screeps_body_utils::combat::tower::towers_attack_damage;
+///
+/// assert_eq!(600 + 450 + 150, towers_attack_damage(&[5, 10, 20]));
+/// ```
+pub const fn towers_attack_damage(tower_ranges: &[u8]) -> u32 {
+    let mut damage = 0;
+    let mut i = 0;
+    while i < tower_ranges.len() {
+        damage += tower_attack_damage(tower_ranges[i]);
+        i += 1;
+    }
+    damage
+}
+
+/// Calculates how many ticks a creep survives under fire from towers at each of the specified
+/// ranges, while healing itself every tick.
+///
+/// Each tick, the tower damage is reduced by the creep's boosted Tough parts and offset by its
+/// own [heal_amount](BodySpec::heal_amount), as per [BodySpec::apply_damage_and_heal]. As parts
+/// are destroyed, the creep loses their Tough reduction and healing.
+///
+/// Returns the tick on which the creep dies, or None if it can tank the towers indefinitely.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::tower::tower_ticks_to_kill;
+///
+/// // 600 damage per tick against 1000 hits
+/// let bodyspec = generate_bodyspec_from_string("5M 5H").unwrap();
+/// assert_eq!(Some(2), tower_ticks_to_kill(&bodyspec, &[5]));
+///
+/// // 150 damage per tick is only partly offset by 60 hits of healing per tick, and less once the
+/// // Heal parts start getting destroyed
+/// assert_eq!(Some(11), tower_ticks_to_kill(&bodyspec, &[20]));
+///
+/// // Boosted Tough parts take the damage down to 45 per tick
+/// let bodyspec = generate_bodyspec_from_string("2T[T3Tough] 5M 5H").unwrap();
+/// assert_eq!(None, tower_ticks_to_kill(&bodyspec, &[20]));
+/// ```
+pub fn tower_ticks_to_kill(bodyspec: &BodySpec, tower_ranges: &[u8]) -> Option<u32> {
+    ticks_to_kill_while_healing(bodyspec, towers_attack_damage(tower_ranges), 0)
+}
+
+/// Whether a creep can indefinitely survive the specified number of towers, all at the same
+/// range, by healing itself and tanking with its Tough parts.
+///
+/// See [tower_ticks_to_kill] for how the damage and healing are applied.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::tower::survives_towers;
+///
+/// let bodyspec = generate_bodyspec_from_string("6T[T3Tough] 10M 10H[T3Heal]").unwrap();
+/// assert!(survives_towers(&bodyspec, 2, 20));
+/// assert!(!survives_towers(&bodyspec, 6, 5));
+/// ```
+pub fn survives_towers(bodyspec: &BodySpec, num_towers: u32, range: u8) -> bool {
+    let damage = tower_attack_damage(range).saturating_mul(num_towers);
+    ticks_to_kill_while_healing(bodyspec, damage, 0).is_none()
+}
+
+/// Calculates the most towers, all at the same range, that a creep can indefinitely survive.
+///
+/// This is capped at [MAX_TOWERS], the most towers a room can have.
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::combat::tower::max_towers_survived;
+///
+/// let bodyspec = generate_bodyspec_from_string("6T[T3Tough] 10M 10H[T3Heal]").unwrap();
+/// assert_eq!(6, max_towers_survived(&bodyspec, 20));
+/// assert_eq!(2, max_towers_survived(&bodyspec, 5));
+/// ```
+pub fn max_towers_survived(bodyspec: &BodySpec, range: u8) -> u32 {
+    // Surviving more towers at the same range is never easier, so stop at the first failure
+    (1..=MAX_TOWERS)
+        .take_while(|num_towers| survives_towers(bodyspec, *num_towers, range))
+        .last()
+        .unwrap_or(0)
+}
@@ -0,0 +1,114 @@
+use screeps::constants::{TOWER_FALLOFF_RANGE, TOWER_OPTIMAL_RANGE, TOWER_POWER_ATTACK};
+
+use super::tower::*;
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn tower_power_falls_off_with_range() {
+    // Every range from point blank to beyond the falloff range, as calculated by the engine
+    let test_data = [
+        (0, 600, 400, 800),
+        (1, 600, 400, 800),
+        (2, 600, 400, 800),
+        (3, 600, 400, 800),
+        (4, 600, 400, 800),
+        (5, 600, 400, 800),
+        (6, 570, 380, 760),
+        (7, 540, 360, 720),
+        (8, 510, 340, 680),
+        (9, 480, 320, 640),
+        (10, 450, 300, 600),
+        (11, 420, 280, 560),
+        (12, 390, 260, 520),
+        (13, 360, 240, 480),
+        (14, 330, 220, 440),
+        (15, 300, 200, 400),
+        (16, 270, 180, 360),
+        (17, 240, 160, 320),
+        (18, 210, 140, 280),
+        (19, 180, 120, 240),
+        (20, 150, 100, 200),
+        (21, 150, 100, 200),
+        (22, 150, 100, 200),
+        (23, 150, 100, 200),
+        (24, 150, 100, 200),
+        (25, 150, 100, 200),
+    ];
+
+    for (range, attack, heal, repair) in test_data {
+        assert_eq!(attack, tower_attack_damage(range), "Range: {}", range);
+        assert_eq!(heal, tower_heal_amount(range), "Range: {}", range);
+        assert_eq!(repair, tower_repair_amount(range), "Range: {}", range);
+    }
+}
+
+#[test]
+fn tower_power_never_increases_with_range() {
+    let mut previous = TOWER_POWER_ATTACK;
+    for range in 0..=(TOWER_FALLOFF_RANGE + 5) {
+        let damage = tower_attack_damage(range);
+        assert!(damage <= previous, "Range: {}", range);
+        if range > TOWER_OPTIMAL_RANGE && range <= TOWER_FALLOFF_RANGE {
+            assert!(damage < previous, "Range: {}", range);
+        }
+        previous = damage;
+    }
+}
+
+#[test]
+fn towers_attack_damage_works_at_compile_time() {
+    const DAMAGE: u32 = towers_attack_damage(&[5, 5, 10, 20, 25, 30]);
+    assert_eq!(600 * 2 + 450 + 150 * 3, DAMAGE);
+    assert_eq!(0, towers_attack_damage(&[]));
+}
+
+#[test]
+fn tower_ticks_to_kill_accounts_for_lost_parts() {
+    // Without healing, the creep dies as soon as its hits run out
+    let unhealed = generate_bodyspec_from_string("10M").unwrap();
+    assert_eq!(Some(7), tower_ticks_to_kill(&unhealed, &[20]));
+    assert_eq!(Some(1), tower_ticks_to_kill(&unhealed, &[5, 5]));
+
+    // Healing keeps up until the Heal parts start getting destroyed
+    let healer = generate_bodyspec_from_string("5M 5H").unwrap();
+    let healed = tower_ticks_to_kill(&healer, &[20]).unwrap();
+    assert!(healed > 7);
+
+    // No towers can never kill anything
+    assert_eq!(None, tower_ticks_to_kill(&unhealed, &[]));
+}
+
+#[test]
+fn survival_depends_on_tough_parts_and_healing() {
+    let unboosted = generate_bodyspec_from_string("6T 10M 10H").unwrap();
+    let boosted_heal = generate_bodyspec_from_string("6T 10M 10H[T3Heal]").unwrap();
+    let fully_boosted = generate_bodyspec_from_string("6T[T3Tough] 10M 10H[T3Heal]").unwrap();
+
+    // 120 healing can't keep up with even a single tower, which deals at least 150 damage
+    assert!(!survives_towers(&unboosted, 1, 20));
+    assert_eq!(0, max_towers_survived(&unboosted, 20));
+
+    // 156 healing can tank a tower at the maximum range, but not any closer
+    let more_heal = generate_bodyspec_from_string("13M 13H").unwrap();
+    assert!(survives_towers(&more_heal, 1, 20));
+    assert!(!survives_towers(&more_heal, 1, 19));
+
+    // 480 healing can tank 3 towers at the maximum range
+    assert_eq!(3, max_towers_survived(&boosted_heal, 20));
+    assert!(!survives_towers(&boosted_heal, 1, 5));
+
+    // Tough parts reduce the damage to 30%
+    assert_eq!(6, max_towers_survived(&fully_boosted, 20));
+    assert_eq!(2, max_towers_survived(&fully_boosted, 5));
+}
+
+#[test]
+fn damaged_creeps_are_easier_to_kill() {
+    let creep = generate_bodyspec_from_string("2T[T3Tough] 5M 5H").unwrap();
+    assert_eq!(None, tower_ticks_to_kill(&creep, &[20]));
+
+    // With the Tough parts gone, 150 damage overwhelms 60 healing
+    let damaged = creep.apply_damage(1000);
+    assert!(tower_ticks_to_kill(&damaged, &[20]).is_some());
+}
@@ -1,7 +1,9 @@
 pub mod boost;
 pub mod body;
+pub mod combat;
 pub mod constants;
 pub mod helpers;
+pub mod spawn_energy;
 
 
 
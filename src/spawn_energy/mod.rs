@@ -0,0 +1,154 @@
+//! Models how much energy is available for spawning creeps at each room controller level.
+//!
+//! Spawning a creep can draw on the energy stored in every spawn and extension in the room, so the
+//! largest body a room can spawn depends on how many of each structure its controller level
+//! allows. Since these are all const fns, bodies can be checked against a controller level at
+//! compile time.
+//!
+//! ```rust
+//! use screeps::Part;
+//! use screeps_body_utils::spawn_energy::{parts_energy_to_spawn, spawn_energy_capacity};
+//!
+//! const RCL_3_WORKER: [Part; 9] = [
+//!     Part::Work, Part::Work, Part::Work,
+//!     Part::Carry, Part::Carry, Part::Carry,
+//!     Part::Move, Part::Move, Part::Move,
+//! ];
+//!
+//! // Fails to compile if the body can't be spawned at RCL 3
+//! const _: () = assert!(parts_energy_to_spawn(&RCL_3_WORKER) <= spawn_energy_capacity(3));
+//! ```
+
+use screeps::constants::{
+    Part,
+    StructureType,
+    SPAWN_ENERGY_CAPACITY,
+    extension_energy_capacity,
+};
+
+use crate::body::BodySpec;
+
+/// The highest room controller level.
+pub const MAX_RCL: u32 = 8;
+
+/// The number of spawns allowed at a room controller level.
+///
+/// ```rust
+/// use screeps_body_utils::spawn_energy::spawn_count;
+///
+/// assert_eq!(1, spawn_count(1));
+/// assert_eq!(2, spawn_count(7));
+/// assert_eq!(3, spawn_count(8));
+/// ```
+pub const fn spawn_count(rcl: u32) -> u32 {
+    StructureType::Spawn.controller_structures(rcl)
+}
+
+/// The number of extensions allowed at a room controller level.
+///
+/// ```rust
+/// use screeps_body_utils::spawn_energy::extension_count;
+///
+/// assert_eq!(0, extension_count(1));
+/// assert_eq!(5, extension_count(2));
+/// assert_eq!(60, extension_count(8));
+/// ```
+pub const fn extension_count(rcl: u32) -> u32 {
+    StructureType::Extension.controller_structures(rcl)
+}
+
+/// The maximum amount of energy available for spawning a single creep at a room controller level,
+/// with every allowed spawn and extension built and full.
+///
+/// Levels above [MAX_RCL] are treated as [MAX_RCL].
+///
+/// ```rust
+/// use screeps_body_utils::spawn_energy::spawn_energy_capacity;
+///
+/// assert_eq!(300, spawn_energy_capacity(1));
+/// assert_eq!(550, spawn_energy_capacity(2));
+/// assert_eq!(5600, spawn_energy_capacity(7));
+/// assert_eq!(12900, spawn_energy_capacity(8));
+/// ```
+pub const fn spawn_energy_capacity(rcl: u32) -> u32 {
+    spawn_count(rcl) * SPAWN_ENERGY_CAPACITY + extension_count(rcl) * extension_energy_capacity(rcl)
+}
+
+/// The lowest room controller level at which the specified amount of energy can be spent on
+/// spawning a single creep.
+///
+/// Returns None if the energy is more than can be spent at [MAX_RCL].
+///
+/// ```rust
+/// use screeps_body_utils::spawn_energy::min_rcl_for_energy;
+///
+/// assert_eq!(Some(1), min_rcl_for_energy(300));
+/// assert_eq!(Some(2), min_rcl_for_energy(301));
+/// assert_eq!(Some(8), min_rcl_for_energy(12900));
+/// assert_eq!(None, min_rcl_for_energy(12901));
+/// ```
+pub const fn min_rcl_for_energy(energy: u32) -> Option<u32> {
+    let mut rcl = 1;
+    while rcl <= MAX_RCL {
+        if energy <= spawn_energy_capacity(rcl) {
+            return Some(rcl);
+        }
+        rcl += 1;
+    }
+    None
+}
+
+/// Calculates the energy needed to spawn the specified parts.
+///
+/// This is the const equivalent of [energy_to_spawn](BodySpec::energy_to_spawn).
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::spawn_energy::parts_energy_to_spawn;
+///
+/// assert_eq!(200, parts_energy_to_spawn(&[Part::Work, Part::Carry, Part::Move]));
+/// ```
+pub const fn parts_energy_to_spawn(parts: &[Part]) -> u32 {
+    let mut energy = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        energy += parts[i].cost();
+        i += 1;
+    }
+    energy
+}
+
+/// The lowest room controller level at which the specified parts can be spawned as a single
+/// creep.
+///
+/// Returns None if the parts cost more energy than can be spent at [MAX_RCL]. The number of parts
+/// isn't checked against [MAX_CREEP_SIZE](screeps::constants::MAX_CREEP_SIZE).
+///
+/// ```rust
+/// use screeps::Part;
+/// use screeps_body_utils::spawn_energy::min_rcl_for_parts;
+///
+/// const RCL: Option<u32> = min_rcl_for_parts(&[Part::Claim, Part::Move]);
+/// assert_eq!(Some(3), RCL);
+/// ```
+pub const fn min_rcl_for_parts(parts: &[Part]) -> Option<u32> {
+    min_rcl_for_energy(parts_energy_to_spawn(parts))
+}
+
+/// The lowest room controller level at which the body can be spawned.
+///
+/// Returns None if the body costs more energy than can be spent at [MAX_RCL].
+///
+/// ```rust
+/// use screeps_body_utils::body::body_specification::generate_bodyspec_from_string;
+/// use screeps_body_utils::spawn_energy::min_rcl_for_body;
+///
+/// let bodyspec = generate_bodyspec_from_string("5W M").unwrap();
+/// assert_eq!(Some(2), min_rcl_for_body(&bodyspec));
+/// ```
+pub fn min_rcl_for_body(bodyspec: &BodySpec) -> Option<u32> {
+    min_rcl_for_energy(bodyspec.energy_to_spawn())
+}
+
+#[cfg(test)]
+mod spawn_energy_tests;
@@ -0,0 +1,59 @@
+use screeps::Part;
+use screeps::constants::MAX_CREEP_SIZE;
+
+use super::*;
+use crate::body::body_scaling::BodyTemplate;
+use crate::body::body_specification::generate_bodyspec_from_string;
+
+
+#[test]
+fn spawn_energy_capacity_matches_known_values() {
+    let expected = [0, 300, 550, 800, 1300, 1800, 2300, 5600, 12900];
+    for (rcl, energy) in expected.into_iter().enumerate() {
+        assert_eq!(energy, spawn_energy_capacity(rcl as u32), "RCL: {}", rcl);
+    }
+
+    // Levels above the maximum are treated as the maximum
+    assert_eq!(spawn_energy_capacity(MAX_RCL), spawn_energy_capacity(MAX_RCL + 1));
+}
+
+#[test]
+fn min_rcl_for_energy_is_inverse_of_capacity() {
+    assert_eq!(Some(1), min_rcl_for_energy(0));
+    for rcl in 1..=MAX_RCL {
+        let capacity = spawn_energy_capacity(rcl);
+        assert_eq!(Some(rcl), min_rcl_for_energy(capacity), "RCL: {}", rcl);
+        if rcl < MAX_RCL {
+            assert_eq!(Some(rcl + 1), min_rcl_for_energy(capacity + 1), "RCL: {}", rcl);
+        }
+    }
+    assert_eq!(None, min_rcl_for_energy(spawn_energy_capacity(MAX_RCL) + 1));
+}
+
+#[test]
+fn parts_energy_to_spawn_matches_bodyspec() {
+    let bodyspec = generate_bodyspec_from_string("T W C A R H L M").unwrap();
+    assert_eq!(bodyspec.energy_to_spawn(), parts_energy_to_spawn(&bodyspec.get_parts()));
+    assert_eq!(0, parts_energy_to_spawn(&[]));
+}
+
+#[test]
+fn min_rcl_for_parts_works_at_compile_time() {
+    const SCOUT: Option<u32> = min_rcl_for_parts(&[Part::Move]);
+    const MAX_HAULER: Option<u32> = min_rcl_for_parts(&[Part::Carry; MAX_CREEP_SIZE as usize]);
+    const TOO_EXPENSIVE: Option<u32> = min_rcl_for_parts(&[Part::Claim; 22]);
+
+    assert_eq!(Some(1), SCOUT);
+    assert_eq!(Some(7), MAX_HAULER);
+    assert_eq!(None, TOO_EXPENSIVE);
+}
+
+#[test]
+fn scaled_bodies_fit_their_rcl() {
+    let template = BodyTemplate::from_strings("", "WCM", "").unwrap();
+    for rcl in 1..=MAX_RCL {
+        let bodyspec = template.scale(spawn_energy_capacity(rcl)).unwrap();
+        let min_rcl = min_rcl_for_body(&bodyspec).unwrap();
+        assert!(min_rcl <= rcl, "RCL: {}", rcl);
+    }
+}